@@ -0,0 +1,121 @@
+#include <assert.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "rust_ecs.h"
+
+typedef struct { float x, y; } Position;
+typedef struct { float dx, dy; } Velocity;
+typedef struct { _Alignas(64) double values[8]; } Matrix;
+
+static void count_moving(EcsEntity entity, const void *const *components, void *user_data) {
+
+    Position position;
+    Velocity velocity;
+    memcpy(&position, components[0], sizeof position);
+    memcpy(&velocity, components[1], sizeof velocity);
+
+    assert(position.x == (float) entity);
+    assert(velocity.dx == 1.0f);
+    *(int *) user_data += 1;
+
+}
+
+static void count_positioned(EcsEntity entity, const void *const *components, void *user_data) {
+
+    Position position;
+    memcpy(&position, components[0], sizeof position);
+
+    assert(position.x == (float) entity);
+    *(int *) user_data += 1;
+
+}
+
+static void sum_matrices(EcsEntity entity, const void *const *components, void *user_data) {
+
+    assert((uintptr_t) components[0] % _Alignof(Matrix) == 0);
+    const Matrix *matrix = components[0];
+
+    assert(matrix->values[7] == (double) entity);
+    *(double *) user_data += matrix->values[7];
+
+}
+
+int main(void) {
+
+    EcsWorldBuilder *builder = ecs_world_builder_new();
+    int32_t position = ecs_world_builder_register_component(builder, "Position", sizeof(Position), _Alignof(Position));
+    int32_t velocity = ecs_world_builder_register_component(builder, "Velocity", sizeof(Velocity), _Alignof(Velocity));
+    int32_t matrix   = ecs_world_builder_register_component(builder, "Matrix", sizeof(Matrix), _Alignof(Matrix));
+    assert(position >= 0 && velocity >= 0 && matrix >= 0);
+    assert(ecs_world_builder_register_component(builder, NULL, 4, 3) == -1);
+
+    EcsWorld *world = ecs_world_builder_build(builder);
+
+    for (int index = 0; index < 4; index++) {
+
+        EcsEntity entity = ecs_entity_spawn(world);
+        Position value   = { (float) entity, 0.0f };
+        assert(ecs_component_set(world, entity, (EcsComponentId) position, &value, sizeof value));
+
+        if (index % 2 == 0) {
+            Velocity speed = { 1.0f, 0.0f };
+            assert(ecs_component_set(world, entity, (EcsComponentId) velocity, &speed, sizeof speed));
+        }
+    }
+
+    assert(!ecs_component_set(world, 0, (EcsComponentId) position, &(Position) { 0 }, 1));
+
+    EcsComponentId unknown = 200;
+    assert(!ecs_component_set(world, 1, unknown, &(Position) { 0 }, sizeof(Position)));
+    assert(ecs_component_get(world, 1, unknown) == NULL);
+    assert(!ecs_component_remove(world, 1, unknown));
+    assert(ecs_component_get(world, 1, (EcsComponentId) (matrix + 1)) == NULL);
+
+    EcsComponentId unknown_ids[2] = { (EcsComponentId) position, unknown };
+    int unused = 0;
+    assert(ecs_query(world, unknown_ids, 2, count_positioned, &unused) == -1);
+    assert(unused == 0);
+
+    Position read;
+    memcpy(&read, ecs_component_get(world, 2, (EcsComponentId) position), sizeof read);
+    assert(read.x == 2.0f);
+
+    EcsComponentId ids[2] = { (EcsComponentId) position, (EcsComponentId) velocity };
+    int moving = 0;
+    assert(ecs_query(world, ids, 2, count_moving, &moving) == 2);
+    assert(moving == 2);
+
+    assert(ecs_entity_despawn(world, 0));
+    assert(!ecs_entity_despawn(world, 0));
+    assert(ecs_component_get(world, 0, (EcsComponentId) position) == NULL);
+    assert(ecs_component_remove(world, 2, (EcsComponentId) velocity));
+
+    moving = 0;
+    assert(ecs_query(world, ids, 2, count_moving, &moving) == 0);
+
+    int positioned = 0;
+    assert(ecs_query(world, ids, 1, count_positioned, &positioned) == 3);
+    assert(positioned == 3);
+
+    for (EcsEntity entity = 1; entity < 4; entity++) {
+        Matrix value = { { 0.0 } };
+        value.values[7] = (double) entity;
+        assert(ecs_component_set(world, entity, (EcsComponentId) matrix, &value, sizeof value));
+
+        const Matrix *typed = ecs_component_get(world, entity, (EcsComponentId) matrix);
+        assert((uintptr_t) typed % _Alignof(Matrix) == 0);
+        assert(typed->values[7] == (double) entity);
+    }
+
+    EcsComponentId matrix_ids[1] = { (EcsComponentId) matrix };
+    double sum = 0.0;
+    assert(ecs_query(world, matrix_ids, 1, sum_matrices, &sum) == 3);
+    assert(sum == 6.0);
+
+    ecs_world_destroy(world);
+    puts("ffi harness passed");
+    return 0;
+
+}
@@ -0,0 +1,93 @@
+#ifndef RUST_ECS_H
+#define RUST_ECS_H
+
+/* Regenerate with `cbindgen --config cbindgen.toml --output include/rust_ecs.h` after changing src/ffi.rs. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+typedef struct EcsWorld EcsWorld;
+
+typedef struct EcsWorldBuilder EcsWorldBuilder;
+
+typedef uint64_t EcsEntity;
+
+typedef uint8_t EcsComponentId;
+
+typedef void (*EcsQueryCallback)(EcsEntity entity, const void *const *components, void *user_data);
+
+EcsWorldBuilder *ecs_world_builder_new(void);
+
+/**
+ * # Safety
+ * `builder` must come from `ecs_world_builder_new` and `name` must be null or a valid C string.
+ */
+int32_t ecs_world_builder_register_component(EcsWorldBuilder *builder,
+                                             const char *name,
+                                             size_t size,
+                                             size_t align);
+
+/**
+ * # Safety
+ * `builder` must come from `ecs_world_builder_new`; it is consumed by this call.
+ */
+EcsWorld *ecs_world_builder_build(EcsWorldBuilder *builder);
+
+/**
+ * # Safety
+ * `builder` must be null or come from `ecs_world_builder_new` and not have been built.
+ */
+void ecs_world_builder_destroy(EcsWorldBuilder *builder);
+
+/**
+ * # Safety
+ * `world` must be null or come from `ecs_world_builder_build`.
+ */
+void ecs_world_destroy(EcsWorld *world);
+
+/**
+ * # Safety
+ * `world` must come from `ecs_world_builder_build`.
+ */
+EcsEntity ecs_entity_spawn(EcsWorld *world);
+
+/**
+ * # Safety
+ * `world` must come from `ecs_world_builder_build`.
+ */
+bool ecs_entity_despawn(EcsWorld *world, EcsEntity entity);
+
+/**
+ * # Safety
+ * `world` must come from `ecs_world_builder_build` and `data` must point to `size` readable bytes.
+ */
+bool ecs_component_set(EcsWorld *world,
+                       EcsEntity entity,
+                       EcsComponentId component,
+                       const void *data,
+                       size_t size);
+
+/**
+ * # Safety
+ * `world` must come from `ecs_world_builder_build`. The returned pointer is only valid until the world is mutated.
+ */
+const void *ecs_component_get(const EcsWorld *world, EcsEntity entity, EcsComponentId component);
+
+/**
+ * # Safety
+ * `world` must come from `ecs_world_builder_build`.
+ */
+bool ecs_component_remove(EcsWorld *world, EcsEntity entity, EcsComponentId component);
+
+/**
+ * # Safety
+ * `world` must come from `ecs_world_builder_build` and `components` must point to `count` component IDs.
+ */
+int64_t ecs_query(const EcsWorld *world,
+                  const EcsComponentId *components,
+                  size_t count,
+                  EcsQueryCallback callback,
+                  void *user_data);
+
+#endif /* RUST_ECS_H */
@@ -0,0 +1,151 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt::Debug;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::hash::Hash;
+
+    use crate::worlds::{World, WorldError};
+    use crate::components::Component;
+    use crate::entities::Entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub trait Bundle: 'static {
+        #[doc(hidden)]
+        fn bit_mask<B: BitField, F: BitField, P: Hash + Eq + Debug>(world: &World<B, F, P>) -> B;
+
+        #[doc(hidden)]
+        fn validate_components<B: BitField, F: BitField, P: Hash + Eq + Debug>(&self, world: &World<B, F, P>, entity: Entity) -> Result<(), WorldError>;
+
+        #[doc(hidden)]
+        fn insert_components<B: BitField, F: BitField, P: Hash + Eq + Debug>(self, world: &mut World<B, F, P>, entity: Entity);
+
+        #[doc(hidden)]
+        fn insert_missing_components<B: BitField, F: BitField, P: Hash + Eq + Debug>(self, world: &mut World<B, F, P>, entity: Entity, bit_mask: B) -> B;
+
+        #[doc(hidden)]
+        fn remove_components<B: BitField, F: BitField, P: Hash + Eq + Debug>(world: &mut World<B, F, P>, entity: Entity);
+    } // trait Bundle
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    macro_rules! impl_tuple_bundle {
+        ($($component:ident),+) => {
+            impl<$($component: Component),+> Bundle for ($($component,)+) {
+                fn bit_mask<B: BitField, F: BitField, P: Hash + Eq + Debug>(world: &World<B, F, P>) -> B {
+                    B::MIN $(| world.component_bit_mask::<$component>())+
+                } // fn bit_mask()
+
+
+                #[allow(non_snake_case)]
+                fn validate_components<B: BitField, F: BitField, P: Hash + Eq + Debug>(&self, world: &World<B, F, P>, entity: Entity) -> Result<(), WorldError> {
+
+                    let ($($component,)+) = self;
+                    $(world.validate_component(entity, $component)?;)+
+                    Ok(())
+
+                } // fn validate_components()
+
+
+                #[allow(non_snake_case)]
+                fn insert_components<B: BitField, F: BitField, P: Hash + Eq + Debug>(self, world: &mut World<B, F, P>, entity: Entity) {
+
+                    let ($($component,)+) = self;
+                    $(world.insert_component_cell(entity, Rc::new(RefCell::new($component)));)+
+
+                } // fn insert_components()
+
+
+                #[allow(non_snake_case)]
+                fn insert_missing_components<B: BitField, F: BitField, P: Hash + Eq + Debug>(
+                    self,
+                    world:    &mut World<B, F, P>,
+                    entity:   Entity,
+                    bit_mask: B,
+                ) -> B {
+
+                    let ($($component,)+) = self;
+                    let mut inserted      = B::MIN;
+                    $(
+                        let component_bit_mask = world.component_bit_mask::<$component>();
+                        if !bit_mask.has_bits(component_bit_mask) {
+                            world.insert_component_cell(entity, Rc::new(RefCell::new($component)));
+                            inserted |= component_bit_mask;
+                        } // if ..
+                    )+
+
+                    inserted
+
+                } // fn insert_missing_components()
+
+
+                fn remove_components<B: BitField, F: BitField, P: Hash + Eq + Debug>(world: &mut World<B, F, P>, entity: Entity) {
+                    $(world.remove_component_cell::<$component>(entity);)+
+                } // fn remove_components()
+            } // impl Bundle ..
+        }; // =>
+    } // macro_rules! impl_tuple_bundle
+
+
+    impl_tuple_bundle!(C0);
+    impl_tuple_bundle!(C0, C1);
+    impl_tuple_bundle!(C0, C1, C2);
+    impl_tuple_bundle!(C0, C1, C2, C3);
+    impl_tuple_bundle!(C0, C1, C2, C3, C4);
+    impl_tuple_bundle!(C0, C1, C2, C3, C4, C5);
+    impl_tuple_bundle!(C0, C1, C2, C3, C4, C5, C6);
+    impl_tuple_bundle!(C0, C1, C2, C3, C4, C5, C6, C7);
+
+
+    #[macro_export]
+    macro_rules! impl_bundle {
+        ($bundle:ident { $($field:ident: $component:ty),+ $(,)? }) => {
+            impl $crate::Bundle for $bundle {
+                fn bit_mask<B: $crate::BitField, F: $crate::BitField, P: ::std::hash::Hash + Eq + ::std::fmt::Debug>(
+                    world: &$crate::World<B, F, P>,
+                ) -> B { <($($component,)+) as $crate::Bundle>::bit_mask(world) }
+
+
+                fn validate_components<B: $crate::BitField, F: $crate::BitField, P: ::std::hash::Hash + Eq + ::std::fmt::Debug>(
+                    &self,
+                    world:  &$crate::World<B, F, P>,
+                    entity: $crate::Entity,
+                ) -> Result<(), $crate::WorldError> {
+                    $(world.validate_component(entity, &self.$field)?;)+
+                    Ok(())
+                } // fn validate_components()
+
+
+                fn insert_components<B: $crate::BitField, F: $crate::BitField, P: ::std::hash::Hash + Eq + ::std::fmt::Debug>(
+                    self,
+                    world:  &mut $crate::World<B, F, P>,
+                    entity: $crate::Entity,
+                ) { $crate::Bundle::insert_components(($(self.$field,)+), world, entity) }
+
+
+                fn insert_missing_components<B: $crate::BitField, F: $crate::BitField, P: ::std::hash::Hash + Eq + ::std::fmt::Debug>(
+                    self,
+                    world:    &mut $crate::World<B, F, P>,
+                    entity:   $crate::Entity,
+                    bit_mask: B,
+                ) -> B { $crate::Bundle::insert_missing_components(($(self.$field,)+), world, entity, bit_mask) }
+
+
+                fn remove_components<B: $crate::BitField, F: $crate::BitField, P: ::std::hash::Hash + Eq + ::std::fmt::Debug>(
+                    world:  &mut $crate::World<B, F, P>,
+                    entity: $crate::Entity,
+                ) { <($($component,)+) as $crate::Bundle>::remove_components(world, entity) }
+            } // impl Bundle ..
+        }; // =>
+    } // macro_rules! impl_bundle
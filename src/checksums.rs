@@ -0,0 +1,98 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::HashMap;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::hash::{Hash, Hasher};
+
+    use crate::components::{Component, ComponentColumn};
+    use crate::dynamics::{DynamicColumn, DynamicComponent, DynamicValue};
+    use crate::entities::Entity;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct StableHasher(u64);
+
+
+    pub(crate) type ChecksumFn = fn(&dyn ComponentColumn, Entity, &mut StableHasher);
+
+
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325u64;
+    const FNV_PRIME:        u64 = 0x0000_0100_0000_01b3u64;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl StableHasher {
+        pub const fn new() -> Self { StableHasher(FNV_OFFSET_BASIS) }
+    } // impl StableHasher
+
+
+    impl Default for StableHasher {
+        fn default() -> Self { StableHasher::new() }
+    } // impl Default ..
+
+
+    impl Hasher for StableHasher {
+        fn finish(&self) -> u64 { self.0 }
+
+
+        fn write(&mut self, bytes: &[u8]) {
+            bytes
+                .iter()
+                .for_each(|byte| self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(FNV_PRIME));
+        } // fn write()
+
+
+        fn write_u16(&mut self, value: u16)     { self.write(&value.to_le_bytes()) }
+        fn write_u32(&mut self, value: u32)     { self.write(&value.to_le_bytes()) }
+        fn write_u64(&mut self, value: u64)     { self.write(&value.to_le_bytes()) }
+        fn write_u128(&mut self, value: u128)   { self.write(&value.to_le_bytes()) }
+        fn write_usize(&mut self, value: usize) { self.write_u64(value as u64) }
+    } // impl Hasher ..
+
+
+    pub(crate) fn checksum_component<C: Component + Hash>(column: &dyn ComponentColumn, entity: Entity, hasher: &mut StableHasher) {
+        if let Some(component) = column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()
+            .and_then(|column| column.get(&entity)) {
+            component.borrow().hash(hasher);
+        } // if ..
+    } // fn checksum_component()
+
+
+    pub(crate) fn checksum_dynamic_component(column: &dyn ComponentColumn, entity: Entity, hasher: &mut StableHasher) {
+
+        let Some(component) = column
+            .as_any()
+            .downcast_ref::<DynamicColumn>()
+            .and_then(|column| column.values.get(&entity)) else { return };
+
+        match component {
+            DynamicComponent::Bytes(bytes) => { hasher.write_u8(0u8); bytes.hash(hasher) },
+            DynamicComponent::Map(values)  => {
+                hasher.write_u8(1u8);
+                values
+                    .iter()
+                    .for_each(|(name, value)| {
+                        name.hash(hasher);
+                        match value {
+                            DynamicValue::Bool(value)  => { hasher.write_u8(0u8); value.hash(hasher) },
+                            DynamicValue::Int(value)   => { hasher.write_u8(1u8); value.hash(hasher) },
+                            DynamicValue::Float(value) => { hasher.write_u8(2u8); value.to_bits().hash(hasher) },
+                            DynamicValue::Text(value)  => { hasher.write_u8(3u8); value.hash(hasher) },
+                        } // match ..
+                    }); // for_each()
+            }, // =>
+        } // match ..
+
+    } // fn checksum_dynamic_component()
@@ -3,11 +3,19 @@
 //#########################
 
     use std::collections::HashMap;
-    use std::any::Any;
+    use std::fmt::Debug;
+    use std::mem::size_of;
+    use std::any::{Any, TypeId};
     use std::rc::Rc;
-    use std::cell::RefCell;
+    use std::cell::{Ref, RefCell, RefMut};
+    use std::ops::Deref;
 
     use crate::entities::Entity;
+    use crate::reflection::{Reflect, ReflectInfo};
+    use crate::dynamics::{DynamicComponent, debug_dynamic_component};
+    use crate::checksums::{ChecksumFn, checksum_dynamic_component};
+    use crate::rollbacks::SnapshotFn;
+    use crate::deltas::ReplicationInfo;
 
 
 //#######################
@@ -16,6 +24,8 @@
 
     pub(crate) trait ComponentCell {
         fn as_any(&self) -> &dyn Any;
+        fn as_ptr(&self) -> *const u8;
+        fn borrow_any(&self) -> Ref<'_, dyn Any>;
     } // trait ComponentCell
 
 
@@ -23,23 +33,245 @@
         fn as_any(&self)         -> &dyn Any;
         fn as_any_mut(&mut self) -> &mut dyn Any;
         fn remove_entity(&mut self, entity: Entity);
+        fn get_ptr(&self, entity: Entity) -> Option<Ptr<'_>>;
+        fn clone_component(&self, entity: Entity, cloning: SharedCloning) -> Option<Box<dyn Any>>;
+        fn insert_component(&mut self, entity: Entity, component: Box<dyn Any>);
+        fn take_component(&mut self, entity: Entity) -> Option<Box<dyn Any>>;
+        fn validate_component(&self, component: &dyn Any, validator: &Validator) -> Result<(), String>;
+        fn get_entities(&self)   -> Vec<Entity>;
+        fn entity_count(&self)   -> usize;
+        fn memory_usage(&self)   -> usize;
     } // trait ComponentColumn
 
 
     pub trait Component: Any + Clone {}
 
 
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+    pub struct ComponentId(pub(crate) u8);
+
+
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, Default, Debug)]
+    pub enum SharedCloning {
+        #[default]
+        Shallow,
+        Deep,
+    } // enum SharedCloning
+
+
+    pub struct Shared<C: Component>(Rc<RefCell<C>>);
+
+
+    #[derive(Clone, Copy)]
+    pub struct Ptr<'world>(PtrTarget<'world>);
+
+
+    #[derive(Clone, Copy)]
+    enum PtrTarget<'world> {
+        Cell(&'world dyn ComponentCell),
+        Dynamic(&'world DynamicComponent),
+    } // enum PtrTarget
+
+
+    pub(crate) struct ComponentInfo {
+        pub(crate) type_id:     Option<TypeId>,
+        pub(crate) name:        String,
+        pub(crate) debug:       Option<DebugFormatter>,
+        pub(crate) reflect:     Option<ReflectInfo>,
+        pub(crate) unique:      bool,
+        pub(crate) validators:  Vec<Validator>,
+        pub(crate) checksum:    Option<ChecksumFn>,
+        pub(crate) rollback:    Option<SnapshotFn>,
+        pub(crate) replication: Option<ReplicationInfo>,
+    } // struct ComponentInfo
+
+
+    pub(crate) type DebugFormatter  = fn(&dyn ComponentColumn, Entity) -> Option<String>;
+    pub(crate) type BoxedComponents = Vec<(usize, Box<dyn Any>)>;
+    pub(crate) type Validator       = Box<dyn Fn(&dyn Any) -> Result<(), String>>;
+
+
 //###############################
 // I M P L E M E N T A T I O N S
 //###############################
 
     impl<C: 'static + Component> ComponentCell for Rc<RefCell<C>> {
         fn as_any(&self) -> &dyn Any { self }
+        fn as_ptr(&self) -> *const u8 { RefCell::as_ptr(self) as *const u8 }
+        fn borrow_any(&self) -> Ref<'_, dyn Any> { Ref::map(RefCell::borrow(self), |component| component as &dyn Any) }
     } // impl ComponentCell ..
 
 
+    impl<C: Component> Shared<C> {
+        pub fn new(component: C) -> Self { Shared(Rc::new(RefCell::new(component))) }
+        pub fn from_cell(component: Rc<RefCell<C>>) -> Self { Shared(component) }
+        pub fn shares_with(&self, component: &Rc<RefCell<C>>) -> bool { Rc::ptr_eq(&self.0, component) }
+        pub fn borrow(&self) -> Ref<'_, C> { self.0.borrow() }
+        pub fn borrow_mut(&self) -> RefMut<'_, C> { self.0.borrow_mut() }
+        pub fn as_cell(&self) -> &Rc<RefCell<C>> { &self.0 }
+        pub fn into_inner(self) -> Result<C, Self> { unwrap_component_cell(self.0).map_err(Shared) }
+
+    } // impl Shared ..
+
+
+    impl<C: Component> Deref for Shared<C> {
+        type Target = Rc<RefCell<C>>;
+        fn deref(&self) -> &Self::Target { &self.0 }
+    } // impl Deref ..
+
+
+    impl ComponentId {
+        pub const fn index(&self) -> u8 { self.0 }
+    } // impl ComponentId
+
+
+    impl<'world> Ptr<'world> {
+        pub(crate) fn dynamic(component: &'world DynamicComponent) -> Self { Ptr(PtrTarget::Dynamic(component)) }
+
+
+        pub fn downcast<C: Component>(&self) -> Option<&'world Rc<RefCell<C>>> {
+            match self.0 {
+                PtrTarget::Cell(cell)   => cell.as_any().downcast_ref::<Rc<RefCell<C>>>(),
+                PtrTarget::Dynamic(_)   => None,
+            } // match ..
+        } // fn downcast()
+
+
+        pub fn as_dynamic(&self) -> Option<&'world DynamicComponent> {
+            match self.0 {
+                PtrTarget::Cell(_)            => None,
+                PtrTarget::Dynamic(component) => Some(component),
+            } // match ..
+        } // fn as_dynamic()
+
+
+        pub(crate) fn validate(&self, validator: &Validator) -> Result<(), String> {
+            match self.0 {
+                PtrTarget::Cell(cell)         => validator(&*cell.borrow_any()),
+                PtrTarget::Dynamic(component) => validator(component),
+            } // match ..
+        } // fn validate()
+
+
+        pub fn as_ptr(&self) -> *const u8 {
+            match self.0 {
+                PtrTarget::Cell(cell)         => cell.as_ptr(),
+                PtrTarget::Dynamic(component) => component
+                    .as_bytes()
+                    .map_or(std::ptr::null(), |bytes| bytes.as_ptr()),
+            } // match ..
+        } // fn as_ptr()
+    } // impl Ptr ..
+
+
+    impl ComponentInfo {
+        pub(crate) fn of<C: Component>() -> Self {
+            ComponentInfo {
+                type_id:     Some(TypeId::of::<C>()),
+                name:        std::any::type_name::<C>().to_string(),
+                debug:       None,
+                reflect:     None,
+                unique:      false,
+                validators:  Vec::new(),
+                checksum:    None,
+                rollback:    None,
+                replication: None,
+            } // ComponentInfo
+        } // fn of()
+
+
+        pub(crate) fn dynamic(name: &str) -> Self {
+            ComponentInfo {
+                type_id:     None,
+                name:        name.to_string(),
+                debug:       Some(debug_dynamic_component),
+                reflect:     None,
+                unique:      false,
+                validators:  Vec::new(),
+                checksum:    Some(checksum_dynamic_component),
+                rollback:    None,
+                replication: None,
+            } // ComponentInfo
+        } // fn dynamic()
+
+
+        pub(crate) fn with_debug<C: Component + Debug>(mut self) -> Self {
+
+            self.debug = Some(debug_component::<C>);
+            self
+
+        } // fn with_debug()
+
+
+        pub(crate) fn with_reflect<C: Component + Reflect>(mut self) -> Self {
+
+            self.reflect = Some(ReflectInfo::of::<C>());
+            self
+
+        } // fn with_reflect()
+
+
+        pub(crate) fn add_validator<C: Component>(&mut self, validator: impl Fn(&C) -> Result<(), String> + 'static) {
+            self.validators.push(Box::new(move |component: &dyn Any| validator(component
+                .downcast_ref::<C>()
+                .expect("Failed to downcast a component!"))));
+        } // fn add_validator()
+    } // impl ComponentInfo
+
+
+    fn debug_component<C: Component + Debug>(column: &dyn ComponentColumn, entity: Entity) -> Option<String> {
+        column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()?
+            .get(&entity)
+            .map(|component| format!("{:?}", component.borrow()))
+    } // fn debug_component()
+
+
+    pub(crate) fn unwrap_component_cell<C: Component>(component: Rc<RefCell<C>>) -> Result<C, Rc<RefCell<C>>> {
+        Rc::try_unwrap(component).map(RefCell::into_inner)
+    } // fn unwrap_component_cell()
+
+
     impl<C: 'static + Component> ComponentColumn for HashMap<Entity, Rc<RefCell<C>>> {
         fn as_any(&self)         -> &dyn Any        { self }
         fn as_any_mut(&mut self) -> &mut dyn Any    { self }
         fn remove_entity(&mut self, entity: Entity) { self.remove(&entity); }
+        fn get_ptr(&self, entity: Entity) -> Option<Ptr<'_>> { self.get(&entity).map(|component| Ptr(PtrTarget::Cell(component))) }
+        fn clone_component(&self, entity: Entity, cloning: SharedCloning) -> Option<Box<dyn Any>> {
+            self.get(&entity)
+                .map(|component| Box::new(match (cloning, Rc::strong_count(component) > 1usize) {
+                    (SharedCloning::Shallow, true) => component.clone(),
+                    _                              => Rc::new(RefCell::new(component.borrow().clone())),
+                }) as Box<dyn Any>)
+        } // fn clone_component()
+
+
+        fn insert_component(&mut self, entity: Entity, component: Box<dyn Any>) {
+            self.insert(entity, *component
+                .downcast::<Rc<RefCell<C>>>()
+                .expect("Failed to downcast a component!"));
+        } // fn insert_component()
+
+
+        fn take_component(&mut self, entity: Entity) -> Option<Box<dyn Any>> {
+            self.remove(&entity)
+                .map(|component| Box::new(component) as Box<dyn Any>)
+        } // fn take_component()
+
+
+        fn validate_component(&self, component: &dyn Any, validator: &Validator) -> Result<(), String> {
+            validator(&*component
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .expect("Failed to downcast a component!")
+                .borrow())
+        } // fn validate_component()
+
+
+        fn get_entities(&self)   -> Vec<Entity>     { self.keys().copied().collect() }
+        fn entity_count(&self)   -> usize           { self.len() }
+        fn memory_usage(&self)   -> usize {
+            self.capacity() * size_of::<(Entity, Rc<RefCell<C>>)>()
+                + self.len() * (size_of::<RefCell<C>>() + 2usize * size_of::<usize>())
+        } // fn memory_usage()
     } // impl ComponentColumn ..
@@ -0,0 +1,309 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, BTreeMap};
+    use std::any::Any;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    use crate::components::ComponentColumn;
+    use crate::rollbacks::{ColumnSnapshot, RollbackColumn, SnapshotFn, snapshot_column};
+    use crate::entities::Entity;
+    use crate::replication::{Replicate, ReplicationRule};
+    use crate::worlds::WorldError;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct WorldDelta<B: BitField> {
+        pub(crate) spawned:    Vec<(Entity, B)>,
+        pub(crate) despawned:  Vec<Entity>,
+        pub(crate) masks:      Vec<(Entity, B)>,
+        pub(crate) components: Vec<(usize, Entity, Vec<u8>)>,
+        pub(crate) full:       bool,
+    } // struct WorldDelta
+
+
+    #[derive(Clone, Default, Debug)]
+    pub struct EntityMap {
+        locals:  HashMap<Entity, Entity>,
+        remotes: HashMap<Entity, Entity>,
+    } // struct EntityMap
+
+
+    #[derive(Clone, Copy)]
+    pub(crate) struct ReplicationInfo {
+        pub(crate) rule:     ReplicationRule,
+        pub(crate) snapshot: SnapshotFn,
+        pub(crate) diff:     DiffFn,
+        pub(crate) read:     ReadFn,
+        pub(crate) decode:   DecodeFn,
+    } // struct ReplicationInfo
+
+
+    struct DeltaReader<'bytes> {
+        bytes: &'bytes [u8],
+    } // struct DeltaReader
+
+
+    pub(crate) type ColumnChanges     = Vec<(usize, HashMap<Entity, Vec<u8>>)>;
+    pub(crate) type DecodedComponents = Vec<(usize, Entity, Box<dyn Any>)>;
+    pub(crate) type DiffFn            = fn(&dyn ComponentColumn, Option<&dyn ColumnSnapshot>) -> Vec<(Entity, Vec<u8>)>;
+    pub(crate) type ReadFn            = fn(&dyn ComponentColumn, Entity) -> Option<Vec<u8>>;
+    pub(crate) type DecodeFn          = fn(&[u8]) -> Option<Box<dyn Any>>;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField> WorldDelta<B> {
+        pub fn is_empty(&self) -> bool {
+            self.spawned.is_empty()
+                && self.despawned.is_empty()
+                && self.masks.is_empty()
+                && self.components.is_empty()
+        } // fn is_empty()
+
+
+        pub fn is_full(&self) -> bool { self.full }
+        pub fn get_spawned_entities(&self) -> Vec<Entity> { self.spawned.iter().map(|(entity, _)| *entity).collect() }
+        pub fn get_despawned_entities(&self) -> Vec<Entity> { self.despawned.clone() }
+        pub fn changed_component_count(&self) -> usize { self.components.len() }
+
+
+        pub fn to_bytes(&self) -> Vec<u8> {
+
+            let mut bytes = vec![u8::from(self.full)];
+
+            write_length(&mut bytes, self.spawned.len());
+            self.spawned
+                .iter()
+                .for_each(|(entity, bit_mask)| { write_entity(&mut bytes, *entity); write_bit_mask(&mut bytes, *bit_mask) });
+
+            write_length(&mut bytes, self.despawned.len());
+            self.despawned
+                .iter()
+                .for_each(|entity| write_entity(&mut bytes, *entity));
+
+            write_length(&mut bytes, self.masks.len());
+            self.masks
+                .iter()
+                .for_each(|(entity, bit_mask)| { write_entity(&mut bytes, *entity); write_bit_mask(&mut bytes, *bit_mask) });
+
+            write_length(&mut bytes, self.components.len());
+            self.components
+                .iter()
+                .for_each(|(index, entity, component)| {
+                    bytes.push(*index as u8);
+                    write_entity(&mut bytes, *entity);
+                    write_length(&mut bytes, component.len());
+                    bytes.extend_from_slice(component);
+                }); // for_each()
+
+            bytes
+
+        } // fn to_bytes()
+
+
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, WorldError> {
+
+            let mut reader = DeltaReader { bytes };
+            let full       = match reader.take(1usize)?[0] {
+                0u8 => false,
+                1u8 => true,
+                _   => return Err(WorldError::MalformedDelta(String::from("invalid full state flag"))),
+            }; // let full
+
+            let spawned = (0..reader.length()?)
+                .map(|_| Ok((reader.entity()?, reader.bit_mask()?)))
+                .collect::<Result<Vec<(Entity, B)>, WorldError>>()?;
+
+            let despawned = (0..reader.length()?)
+                .map(|_| reader.entity())
+                .collect::<Result<Vec<Entity>, WorldError>>()?;
+
+            let masks = (0..reader.length()?)
+                .map(|_| Ok((reader.entity()?, reader.bit_mask()?)))
+                .collect::<Result<Vec<(Entity, B)>, WorldError>>()?;
+
+            let components = (0..reader.length()?)
+                .map(|_| {
+                    let index  = reader.take(1usize)?[0] as usize;
+                    let entity = reader.entity()?;
+                    let length = reader.length()?;
+                    Ok((index, entity, reader.take(length)?.to_vec()))
+                }).collect::<Result<Vec<(usize, Entity, Vec<u8>)>, WorldError>>()?;
+
+            if !reader.bytes.is_empty() { return Err(WorldError::MalformedDelta(format!("{} trailing bytes", reader.bytes.len()))) }
+
+            Ok(WorldDelta {
+                spawned,
+                despawned,
+                masks,
+                components,
+                full,
+            }) // WorldDelta
+
+        } // fn from_bytes()
+
+
+        pub(crate) fn apply_to_known(&self, known: &mut BTreeMap<Entity, B>) {
+
+            self.despawned
+                .iter()
+                .for_each(|entity| { known.remove(entity); });
+
+            known.extend(self.spawned
+                .iter()
+                .chain(self.masks.iter())
+                .copied());
+
+        } // fn apply_to_known()
+
+    } // impl WorldDelta ..
+
+
+    impl EntityMap {
+        pub fn new() -> Self { Self::default() }
+        pub fn len(&self) -> usize { self.locals.len() }
+        pub fn is_empty(&self) -> bool { self.locals.is_empty() }
+        pub fn get_local_entity(&self, entity: Entity) -> Option<Entity> { self.locals.get(&entity).copied() }
+        pub fn get_server_entity(&self, entity: Entity) -> Option<Entity> { self.remotes.get(&entity).copied() }
+        pub fn get_server_entities(&self) -> Vec<Entity> { self.locals.keys().copied().collect() }
+
+
+        pub(crate) fn insert(&mut self, server: Entity, local: Entity) {
+
+            if let Some(previous) = self.locals.insert(server, local) { self.remotes.remove(&previous); }
+            self.remotes.insert(local, server);
+
+        } // fn insert()
+
+
+        pub(crate) fn remove(&mut self, server: Entity) -> Option<Entity> {
+
+            let local = self.locals.remove(&server)?;
+            self.remotes.remove(&local);
+            Some(local)
+
+        } // fn remove()
+    } // impl EntityMap
+
+
+    impl ReplicationInfo {
+        pub(crate) fn of<C: Replicate>(rule: ReplicationRule) -> Self {
+            ReplicationInfo {
+                rule,
+                snapshot: snapshot_column::<C>,
+                diff:     diff_column::<C>,
+                read:     read_component::<C>,
+                decode:   decode_component::<C>,
+            } // ReplicationInfo
+        } // fn of()
+    } // impl ReplicationInfo
+
+
+    impl DeltaReader<'_> {
+        fn take(&mut self, count: usize) -> Result<&[u8], WorldError> {
+
+            if self.bytes.len() < count { return Err(WorldError::MalformedDelta(String::from("unexpected end of bytes"))) }
+
+            let (taken, rest) = self.bytes.split_at(count);
+            self.bytes        = rest;
+            Ok(taken)
+
+        } // fn take()
+
+
+        fn length(&mut self) -> Result<usize, WorldError> {
+            Ok(u32::from_le_bytes(self.take(4usize)?
+                .try_into()
+                .expect("Failed to read a length!")) as usize)
+        } // fn length()
+
+
+        fn entity(&mut self) -> Result<Entity, WorldError> {
+            Ok(Entity::new(u64::from_le_bytes(self.take(8usize)?
+                .try_into()
+                .expect("Failed to read an entity!")) as usize))
+        } // fn entity()
+
+
+        fn bit_mask<B: BitField>(&mut self) -> Result<B, WorldError> {
+
+            let bytes = self.take((B::BITS as usize).div_ceil(8usize))?;
+            Ok((0..B::BITS)
+                .filter(|bit| bytes[*bit as usize / 8usize] & (1u8 << (bit % 8u8)) != 0u8)
+                .fold(B::MIN, |bit_mask, bit| bit_mask | B::bit(bit)))
+
+        } // fn bit_mask()
+    } // impl DeltaReader ..
+
+
+    fn write_length(bytes: &mut Vec<u8>, length: usize) { bytes.extend_from_slice(&(length as u32).to_le_bytes()) }
+    fn write_entity(bytes: &mut Vec<u8>, entity: Entity) { bytes.extend_from_slice(&(entity.id() as u64).to_le_bytes()) }
+
+
+    fn write_bit_mask<B: BitField>(bytes: &mut Vec<u8>, bit_mask: B) {
+
+        let mut encoded = vec![0u8; (B::BITS as usize).div_ceil(8usize)];
+        (0..B::BITS)
+            .filter(|bit| bit_mask.has_bits(B::bit(*bit)))
+            .for_each(|bit| encoded[bit as usize / 8usize] |= 1u8 << (bit % 8u8));
+
+        bytes.extend(encoded);
+
+    } // fn write_bit_mask()
+
+
+    fn encode_component<C: Replicate>(component: &C) -> Vec<u8> {
+
+        let mut bytes = Vec::new();
+        component.encode(&mut bytes);
+        bytes
+
+    } // fn encode_component()
+
+
+    fn diff_column<C: Replicate>(column: &dyn ComponentColumn, previous: Option<&dyn ColumnSnapshot>) -> Vec<(Entity, Vec<u8>)> {
+
+        let previous = previous.and_then(|previous| previous.as_any().downcast_ref::<RollbackColumn<C>>());
+        let column   = column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()
+            .expect("Failed to downcast a component column!");
+
+        let mut changes = column
+            .iter()
+            .filter(|(entity, component)| previous
+                .and_then(|previous| previous.0.get(entity))
+                .is_none_or(|previous| **previous != *component.borrow()))
+            .map(|(entity, component)| (*entity, encode_component(&*component.borrow())))
+            .collect::<Vec<(Entity, Vec<u8>)>>();
+
+        changes.sort_by_key(|(entity, _)| *entity);
+        changes
+
+    } // fn diff_column()
+
+
+    fn read_component<C: Replicate>(column: &dyn ComponentColumn, entity: Entity) -> Option<Vec<u8>> {
+        column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()
+            .expect("Failed to downcast a component column!")
+            .get(&entity)
+            .map(|component| encode_component(&*component.borrow()))
+    } // fn read_component()
+
+
+    fn decode_component<C: Replicate>(bytes: &[u8]) -> Option<Box<dyn Any>> {
+        C::decode(bytes).map(|component| Box::new(Rc::new(RefCell::new(component))) as Box<dyn Any>)
+    } // fn decode_component()
@@ -0,0 +1,310 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, BTreeMap};
+    use std::any::Any;
+    use std::mem::size_of;
+    use std::fmt::{self, Debug, Display};
+    use std::error::Error;
+    use std::alloc::{self, Layout};
+    use std::ptr::{self, NonNull};
+    use std::ops::{Deref, DerefMut};
+    use std::hash::{Hash, Hasher};
+    use std::slice;
+
+    use crate::components::{ComponentColumn, ComponentId, Ptr, SharedCloning, Validator};
+    use crate::entities::Entity;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum ComponentLayout {
+        Bytes {
+            size:  usize,
+            align: usize,
+        }, // Bytes
+        Map(Vec<(String, ValueKind)>),
+    } // enum ComponentLayout
+
+
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    pub enum ValueKind {
+        Bool,
+        Int,
+        Float,
+        Text,
+    } // enum ValueKind
+
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum DynamicValue {
+        Bool(bool),
+        Int(i64),
+        Float(f64),
+        Text(String),
+    } // enum DynamicValue
+
+
+    pub struct AlignedBytes {
+        ptr:   NonNull<u8>,
+        len:   usize,
+        align: usize,
+    } // struct AlignedBytes
+
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum DynamicComponent {
+        Bytes(AlignedBytes),
+        Map(BTreeMap<String, DynamicValue>),
+    } // enum DynamicComponent
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum DynamicError {
+        UnknownComponent(ComponentId),
+        LayoutMismatch(ComponentId),
+    } // enum DynamicError
+
+
+    pub(crate) struct DynamicColumn {
+        pub(crate) layout: ComponentLayout,
+        pub(crate) values: HashMap<Entity, DynamicComponent>,
+    } // struct DynamicColumn
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl ComponentLayout {
+        pub fn matches(&self, component: &DynamicComponent) -> bool {
+            match (self, component) {
+                (ComponentLayout::Bytes { size, .. }, DynamicComponent::Bytes(bytes)) => bytes.len() == *size,
+                (ComponentLayout::Map(fields), DynamicComponent::Map(values))         => fields.len() == values.len()
+                    && fields
+                        .iter()
+                        .all(|(name, kind)| values
+                            .get(name)
+                            .is_some_and(|value| value.kind() == *kind)),
+                _ => false,
+            } // match ..
+        } // fn matches()
+    } // impl ComponentLayout
+
+
+    impl DynamicValue {
+        pub const fn kind(&self) -> ValueKind {
+            match self {
+                DynamicValue::Bool(_)  => ValueKind::Bool,
+                DynamicValue::Int(_)   => ValueKind::Int,
+                DynamicValue::Float(_) => ValueKind::Float,
+                DynamicValue::Text(_)  => ValueKind::Text,
+            } // match ..
+        } // fn kind()
+    } // impl DynamicValue
+
+
+    impl AlignedBytes {
+        pub fn new(bytes: &[u8], align: usize) -> Self {
+
+            let layout = bytes_layout(bytes.len(), align);
+            let ptr    = NonNull::new(unsafe { alloc::alloc(layout) })
+                .unwrap_or_else(|| alloc::handle_alloc_error(layout));
+
+            unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.as_ptr(), bytes.len()) }
+
+            AlignedBytes {
+                ptr,
+                len: bytes.len(),
+                align,
+            } // AlignedBytes
+
+        } // fn new()
+
+
+        pub fn align(&self) -> usize { self.align }
+        pub fn as_slice(&self) -> &[u8] { unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) } }
+        pub fn as_mut_slice(&mut self) -> &mut [u8] { unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) } }
+
+
+        pub(crate) fn realign(self, align: usize) -> Self {
+            match self.align >= align {
+                true  => self,
+                false => AlignedBytes::new(&self, align),
+            } // match ..
+        } // fn realign()
+    } // impl AlignedBytes
+
+
+    impl Drop for AlignedBytes {
+        fn drop(&mut self) { unsafe { alloc::dealloc(self.ptr.as_ptr(), bytes_layout(self.len, self.align)) } }
+    } // impl Drop ..
+
+
+    impl Clone for AlignedBytes {
+        fn clone(&self) -> Self { AlignedBytes::new(self, self.align) }
+    } // impl Clone ..
+
+
+    impl Deref for AlignedBytes {
+        type Target = [u8];
+        fn deref(&self) -> &Self::Target { self.as_slice() }
+    } // impl Deref ..
+
+
+    impl DerefMut for AlignedBytes {
+        fn deref_mut(&mut self) -> &mut Self::Target { self.as_mut_slice() }
+    } // impl DerefMut ..
+
+
+    impl PartialEq for AlignedBytes {
+        fn eq(&self, other: &Self) -> bool { self.as_slice() == other.as_slice() }
+    } // impl PartialEq ..
+
+
+    impl Eq for AlignedBytes {}
+
+
+    impl Hash for AlignedBytes {
+        fn hash<H: Hasher>(&self, state: &mut H) { self.as_slice().hash(state) }
+    } // impl Hash ..
+
+
+    impl Debug for AlignedBytes {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { Debug::fmt(self.as_slice(), f) }
+    } // impl Debug ..
+
+
+    impl From<Vec<u8>> for AlignedBytes {
+        fn from(bytes: Vec<u8>) -> Self { AlignedBytes::new(&bytes, 1usize) }
+    } // impl From ..
+
+
+    impl From<&[u8]> for AlignedBytes {
+        fn from(bytes: &[u8]) -> Self { AlignedBytes::new(bytes, 1usize) }
+    } // impl From ..
+
+
+    unsafe impl Send for AlignedBytes {}
+    unsafe impl Sync for AlignedBytes {}
+
+
+    fn bytes_layout(len: usize, align: usize) -> Layout {
+        Layout::from_size_align(len.max(1usize), align)
+            .expect("Attempted to allocate bytes with an invalid alignment!")
+    } // fn bytes_layout()
+
+
+    impl DynamicComponent {
+        pub fn as_bytes(&self) -> Option<&[u8]> {
+            match self {
+                DynamicComponent::Bytes(bytes) => Some(bytes),
+                DynamicComponent::Map(_)       => None,
+            } // match ..
+        } // fn as_bytes()
+
+
+        pub fn get(&self, field: &str) -> Option<&DynamicValue> {
+            match self {
+                DynamicComponent::Bytes(_)   => None,
+                DynamicComponent::Map(values) => values.get(field),
+            } // match ..
+        } // fn get()
+    } // impl DynamicComponent
+
+
+    impl Display for DynamicError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                DynamicError::UnknownComponent(id) => write!(f, "The component no.{} is not a registered dynamic component!", id.0),
+                DynamicError::LayoutMismatch(id)   => write!(f, "The value does not match the layout of the dynamic component no.{}!", id.0),
+            } // match ..
+        } // fn fmt()
+    } // impl Display ..
+
+
+    impl Error for DynamicError {}
+
+
+    impl DynamicColumn {
+        pub(crate) fn new(layout: ComponentLayout) -> Self {
+            DynamicColumn {
+                layout,
+                values: HashMap::default(),
+            } // DynamicColumn
+        } // fn new()
+
+
+        pub(crate) fn insert_value(&mut self, entity: Entity, component: DynamicComponent) {
+            self.values.insert(entity, match (&self.layout, component) {
+                (ComponentLayout::Bytes { align, .. }, DynamicComponent::Bytes(bytes)) => DynamicComponent::Bytes(bytes.realign(*align)),
+                (_, component)                                                        => component,
+            }); // insert()
+        } // fn insert_value()
+    } // impl DynamicColumn
+
+
+    impl ComponentColumn for DynamicColumn {
+        fn as_any(&self)         -> &dyn Any        { self }
+        fn as_any_mut(&mut self) -> &mut dyn Any    { self }
+        fn remove_entity(&mut self, entity: Entity) { self.values.remove(&entity); }
+        fn get_ptr(&self, entity: Entity) -> Option<Ptr<'_>> { self.values.get(&entity).map(Ptr::dynamic) }
+        fn clone_component(&self, entity: Entity, _: SharedCloning) -> Option<Box<dyn Any>> {
+            self.values
+                .get(&entity)
+                .map(|component| Box::new(component.clone()) as Box<dyn Any>)
+        } // fn clone_component()
+
+
+        fn insert_component(&mut self, entity: Entity, component: Box<dyn Any>) {
+            self.insert_value(entity, *component
+                .downcast::<DynamicComponent>()
+                .expect("Failed to downcast a component!"));
+        } // fn insert_component()
+
+
+        fn take_component(&mut self, entity: Entity) -> Option<Box<dyn Any>> {
+            self.values
+                .remove(&entity)
+                .map(|component| Box::new(component) as Box<dyn Any>)
+        } // fn take_component()
+
+
+        fn validate_component(&self, component: &dyn Any, validator: &Validator) -> Result<(), String> {
+            validator(component
+                .downcast_ref::<DynamicComponent>()
+                .expect("Failed to downcast a component!"))
+        } // fn validate_component()
+
+
+        fn get_entities(&self)   -> Vec<Entity>     { self.values.keys().copied().collect() }
+        fn entity_count(&self)   -> usize           { self.values.len() }
+        fn memory_usage(&self)   -> usize {
+            self.values.capacity() * size_of::<(Entity, DynamicComponent)>()
+                + self.values
+                    .values()
+                    .map(|component| match component {
+                        DynamicComponent::Bytes(bytes) => bytes.len(),
+                        DynamicComponent::Map(values)  => values
+                            .iter()
+                            .map(|(name, value)| name.capacity() + size_of::<DynamicValue>() + match value {
+                                DynamicValue::Text(text) => text.capacity(),
+                                _                        => 0usize,
+                            }).sum::<usize>(),
+                    }).sum::<usize>()
+        } // fn memory_usage()
+    } // impl ComponentColumn ..
+
+
+    pub(crate) fn debug_dynamic_component(column: &dyn ComponentColumn, entity: Entity) -> Option<String> {
+        column
+            .as_any()
+            .downcast_ref::<DynamicColumn>()?
+            .values
+            .get(&entity)
+            .map(|component| format!("{:?}", component))
+    } // fn debug_dynamic_component()
@@ -7,8 +7,11 @@
     use std::cell::RefCell;
     use std::hash::Hash;
 
-    use crate::worlds::World;
-    use crate::components::Component;
+    use crate::worlds::{World, WorldError};
+    use crate::components::{Component, ComponentId};
+    use crate::bundles::Bundle;
+    use crate::flags::Flag;
+    use crate::dynamics::DynamicComponent;
 
     use rusty_toolkit::BitField;
 
@@ -17,7 +20,7 @@
 // D E F I N I T I O N S
 //#######################
 
-    #[derive(Clone, Copy, Hash, PartialEq, Eq, Default, Debug)]
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
     pub struct Entity(EntityId);
 
 
@@ -35,6 +38,12 @@
 // I M P L E M E N T A T I O N S
 //###############################
 
+    impl Entity {
+        pub(crate) const fn new(id: EntityId) -> Self { Entity(id) }
+        pub const fn id(&self) -> usize { self.0 }
+    } // impl Entity
+
+
     impl<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> EntityBuilder<'world, B, F, P> {
         pub(crate) fn new(
             id:    EntityId,
@@ -58,6 +67,25 @@
         } // fn with_shared_component()
 
 
+        pub fn with_bundle<T: Bundle>(mut self, bundle: T) -> Self {
+
+            self.world.add_bundle_to_entity_builder(bundle, self.entity, &mut self.bit_mask);
+            self
+
+        } // fn with_bundle()
+
+
+        pub fn with_dynamic_component(mut self, id: ComponentId, component: DynamicComponent) -> Self {
+
+            self.world
+                .add_dynamic_component_to_entity_builder(id, component, self.entity, &mut self.bit_mask)
+                .unwrap_or_else(|error| panic!("{}", error));
+
+            self
+
+        } // fn with_dynamic_component()
+
+
         pub fn with_flag(
             mut self,
             flag:    F,
@@ -70,10 +98,27 @@
         } // fn with_flag()
 
 
-        pub fn build(self) -> Entity {
+        pub fn with_typed_flag<T: Flag>(mut self, flag: T) -> Self {
+
+            self.world.add_typed_flag_to_entity_builder(flag, &mut self.bit_mask);
+            self
+
+        } // fn with_typed_flag()
+
+
+        pub fn build(self) -> Entity { self.try_build().unwrap_or_else(|error| panic!("{}", error)) }
+
+
+        pub fn try_build(self) -> Result<Entity, WorldError> {
+
+            let bit_mask = self.world.insert_required_components(self.entity, self.bit_mask);
+            if let Err(error) = self.world.validate_components(self.entity, bit_mask) {
+                self.world.discard_entity_components(self.entity, bit_mask);
+                return Err(error)
+            } // if ..
 
-            self.world.add_entity(self.entity, self.bit_mask);
-            self.entity
+            self.world.add_entity(self.entity, bit_mask);
+            Ok(self.entity)
 
-        } // fn build()
+        } // fn try_build()
     } // impl EntityBuilder ..
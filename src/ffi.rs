@@ -0,0 +1,228 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::ffi::{c_void, CStr};
+    use std::os::raw::c_char;
+    use std::ptr;
+    use std::slice;
+    use std::alloc::Layout;
+
+    use crate::worlds::{World, WorldBuilder};
+    use crate::components::ComponentId;
+    use crate::entities::Entity;
+    use crate::dynamics::{ComponentLayout, DynamicComponent, AlignedBytes};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub struct EcsWorldBuilder(WorldBuilder<u64, u8, u32>);
+    pub struct EcsWorld(World<u64, u8, u32>);
+
+
+    pub type EcsEntity        = u64;
+    pub type EcsComponentId   = u8;
+    pub type EcsQueryCallback = extern "C" fn(entity: EcsEntity, components: *const *const c_void, user_data: *mut c_void);
+
+
+    const MAX_COMPONENT_COUNT: usize = u64::BITS as usize;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    #[no_mangle]
+    pub extern "C" fn ecs_world_builder_new() -> *mut EcsWorldBuilder {
+        Box::into_raw(Box::new(EcsWorldBuilder(World::builder())))
+    } // fn ecs_world_builder_new()
+
+
+    /// # Safety
+    /// `builder` must come from `ecs_world_builder_new` and `name` must be null or a valid C string.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_world_builder_register_component(
+        builder: *mut EcsWorldBuilder,
+        name:    *const c_char,
+        size:    usize,
+        align:   usize,
+    ) -> i32 {
+
+        let Some(builder) = builder.as_mut() else { return -1i32 };
+        if Layout::from_size_align(size, align).is_err() || builder.0.component_count() >= MAX_COMPONENT_COUNT { return -1i32 }
+
+        let name = match name.is_null() {
+            true  => format!("component_{}", builder.0.component_count()),
+            false => CStr::from_ptr(name).to_string_lossy().into_owned(),
+        }; // let name
+
+        i32::from(builder.0.register_dynamic_component(&name, ComponentLayout::Bytes { size, align }).index())
+
+    } // fn ecs_world_builder_register_component()
+
+
+    /// # Safety
+    /// `builder` must come from `ecs_world_builder_new`; it is consumed by this call.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_world_builder_build(builder: *mut EcsWorldBuilder) -> *mut EcsWorld {
+        match builder.is_null() {
+            true  => ptr::null_mut(),
+            false => Box::into_raw(Box::new(EcsWorld(Box::from_raw(builder).0.build()))),
+        } // match ..
+    } // fn ecs_world_builder_build()
+
+
+    /// # Safety
+    /// `builder` must be null or come from `ecs_world_builder_new` and not have been built.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_world_builder_destroy(builder: *mut EcsWorldBuilder) {
+        if !builder.is_null() { drop(Box::from_raw(builder)) }
+    } // fn ecs_world_builder_destroy()
+
+
+    /// # Safety
+    /// `world` must be null or come from `ecs_world_builder_build`.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_world_destroy(world: *mut EcsWorld) {
+        if !world.is_null() { drop(Box::from_raw(world)) }
+    } // fn ecs_world_destroy()
+
+
+    /// # Safety
+    /// `world` must come from `ecs_world_builder_build`.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_entity_spawn(world: *mut EcsWorld) -> EcsEntity {
+        match world.as_mut() {
+            Some(world) => world.0.new_entity().build().id() as EcsEntity,
+            None        => EcsEntity::MAX,
+        } // match ..
+    } // fn ecs_entity_spawn()
+
+
+    /// # Safety
+    /// `world` must come from `ecs_world_builder_build`.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_entity_despawn(world: *mut EcsWorld, entity: EcsEntity) -> bool {
+
+        let Some(world) = world.as_mut() else { return false };
+        let entity      = Entity::new(entity as usize);
+        if !world.0.contains_entity(entity) { return false }
+
+        world.0.delete_entity(entity);
+        true
+
+    } // fn ecs_entity_despawn()
+
+
+    /// # Safety
+    /// `world` must come from `ecs_world_builder_build` and `data` must point to `size` readable bytes.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_component_set(
+        world:     *mut EcsWorld,
+        entity:    EcsEntity,
+        component: EcsComponentId,
+        data:      *const c_void,
+        size:      usize,
+    ) -> bool {
+
+        let Some(world) = world.as_mut() else { return false };
+        let entity      = Entity::new(entity as usize);
+        if data.is_null() || !world.0.contains_entity(entity) || !is_registered(world, component) { return false }
+
+        let bytes = AlignedBytes::from(slice::from_raw_parts(data as *const u8, size));
+        world.0
+            .set_dynamic_component(entity, ComponentId(component), DynamicComponent::Bytes(bytes))
+            .is_ok()
+
+    } // fn ecs_component_set()
+
+
+    /// # Safety
+    /// `world` must come from `ecs_world_builder_build`. The returned pointer is only valid until the world is mutated.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_component_get(
+        world:     *const EcsWorld,
+        entity:    EcsEntity,
+        component: EcsComponentId,
+    ) -> *const c_void {
+        match world.as_ref() {
+            Some(world) if is_registered(world, component) => world.0
+                .get_dynamic_component(Entity::new(entity as usize), ComponentId(component))
+                .and_then(DynamicComponent::as_bytes)
+                .map_or(ptr::null(), |bytes| bytes.as_ptr() as *const c_void),
+            _ => ptr::null(),
+        } // match ..
+    } // fn ecs_component_get()
+
+
+    /// # Safety
+    /// `world` must come from `ecs_world_builder_build`.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_component_remove(
+        world:     *mut EcsWorld,
+        entity:    EcsEntity,
+        component: EcsComponentId,
+    ) -> bool {
+
+        let Some(world) = world.as_mut() else { return false };
+        let entity      = Entity::new(entity as usize);
+        if !world.0.contains_entity(entity) || !is_registered(world, component) { return false }
+
+        world.0
+            .remove_dynamic_component(entity, ComponentId(component))
+            .is_some()
+
+    } // fn ecs_component_remove()
+
+
+    /// # Safety
+    /// `world` must come from `ecs_world_builder_build` and `components` must point to `count` component IDs.
+    #[no_mangle]
+    pub unsafe extern "C" fn ecs_query(
+        world:      *const EcsWorld,
+        components: *const EcsComponentId,
+        count:      usize,
+        callback:   EcsQueryCallback,
+        user_data:  *mut c_void,
+    ) -> i64 {
+
+        let Some(world) = world.as_ref() else { return -1i64 };
+        if components.is_null() && count > 0usize { return -1i64 }
+
+        let ids = match count {
+            0 => Vec::new(),
+            _ => slice::from_raw_parts(components, count).to_vec(),
+        }; // let ids
+
+        if !ids.iter().all(|id| is_registered(world, *id)) { return -1i64 }
+        let ids = ids
+            .into_iter()
+            .map(ComponentId)
+            .collect::<Vec<ComponentId>>();
+
+        let rows = ids
+            .iter()
+            .fold(world.0.new_query(), |query, id| query.with_component_id(*id))
+            .build()
+            .get_rows_by_id(&ids);
+
+        rows.iter()
+            .for_each(|(entity, components)| {
+                let pointers = components
+                    .iter()
+                    .map(|component| component.as_ptr() as *const c_void)
+                    .collect::<Vec<*const c_void>>();
+                callback(entity.id() as EcsEntity, pointers.as_ptr(), user_data);
+            }); // for_each()
+
+        rows.len() as i64
+
+    } // fn ecs_query()
+
+
+    fn is_registered(world: &EcsWorld, component: EcsComponentId) -> bool {
+        (component as usize) < world.0.component_count()
+            && world.0.get_dynamic_component_layout(ComponentId(component)).is_some()
+    } // fn is_registered()
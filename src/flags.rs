@@ -0,0 +1,98 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::any::Any;
+    use std::fmt::Debug;
+    use std::ops::Range;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub trait Flag: Any + Copy + Eq + Debug {
+        const VARIANTS: &'static [Self];
+        const BITS: u8 = flag_width(Self::VARIANTS.len());
+
+        fn index(self) -> u8 {
+            Self::VARIANTS
+                .iter()
+                .position(|variant| *variant == self)
+                .expect("Attempted to use a flag variant that was not listed!") as u8
+        } // fn index()
+
+
+        fn from_index(index: u8) -> Option<Self> { Self::VARIANTS.get(usize::from(index)).copied() }
+
+    } // trait Flag
+
+
+    #[derive(Clone)]
+    pub(crate) struct FlagType {
+        pub(crate) name:     &'static str,
+        pub(crate) range:    Range<u8>,
+        pub(crate) describe: fn(u8) -> Option<String>,
+    } // struct FlagType
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl FlagType {
+        pub(crate) fn of<T: Flag>() -> Self {
+            FlagType {
+                name:     std::any::type_name::<T>(),
+                range:    0u8..T::BITS,
+                describe: describe_flag::<T>,
+            } // FlagType
+        } // fn of()
+    } // impl FlagType
+
+
+    fn describe_flag<T: Flag>(index: u8) -> Option<String> { T::from_index(index).map(|variant| format!("{:?}", variant)) }
+
+
+    pub(crate) const fn flag_width(variant_count: usize) -> u8 {
+        match variant_count {
+            0 | 1 => 1u8,
+            count => (usize::BITS - (count - 1).leading_zeros()) as u8,
+        } // match ..
+    } // fn flag_width()
+
+
+    pub(crate) fn flag_index_bit_mask<B: BitField>(range: Range<u8>, index: u8) -> B {
+        range
+            .enumerate()
+            .filter(|(bit, _)| index & (1u8 << bit) != 0)
+            .fold(B::MIN, |bit_mask, (_, position)| bit_mask | B::bit(position))
+    } // fn flag_index_bit_mask()
+
+
+    pub(crate) fn read_flag_index<B: BitField>(bit_mask: B, range: Range<u8>) -> u8 {
+        range
+            .enumerate()
+            .filter(|(_, position)| bit_mask.has_bits(B::bit(*position)))
+            .fold(0u8, |index, (bit, _)| index | (1u8 << bit))
+    } // fn read_flag_index()
+
+
+    pub(crate) fn read_flag_variant<B: BitField>(bit_mask: B, range: Range<u8>) -> B {
+        range
+            .enumerate()
+            .filter(|(_, position)| bit_mask.has_bits(B::bit(*position)))
+            .fold(B::MIN, |variant, (bit, _)| variant | B::bit(bit as u8))
+    } // fn read_flag_variant()
+
+
+    #[macro_export]
+    macro_rules! impl_flag {
+        ($flag:ident { $($variant:ident),+ $(,)? }) => {
+            impl $crate::Flag for $flag {
+                const VARIANTS: &'static [Self] = &[$($flag::$variant),+];
+            } // impl Flag ..
+        }; // =>
+    } // macro_rules! impl_flag
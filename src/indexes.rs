@@ -0,0 +1,171 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, BTreeMap};
+    use std::any::Any;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::hash::Hash;
+    use std::ops::Bound;
+
+    use crate::components::{Component, ComponentColumn};
+    use crate::entities::Entity;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub trait ComponentIndex: 'static {
+        type Component: Component;
+        type Key: Clone + Eq + 'static;
+
+        fn key(component: &Self::Component) -> Self::Key;
+
+    } // trait ComponentIndex
+
+
+    pub(crate) trait IndexStorage {
+        fn update(&mut self, column: &dyn ComponentColumn, entity: Entity);
+        fn remove_entity(&mut self, entity: Entity);
+        fn lookup(&self, key: &dyn Any) -> Vec<Entity>;
+        fn lookup_range(&self, start: Bound<&dyn Any>, end: Bound<&dyn Any>) -> Option<Vec<Entity>>;
+    } // trait IndexStorage
+
+
+    pub(crate) struct HashIndex<I: ComponentIndex> {
+        keys:     HashMap<Entity, I::Key>,
+        entities: HashMap<I::Key, Vec<Entity>>,
+    } // struct HashIndex
+
+
+    pub(crate) struct OrderedIndex<I: ComponentIndex> {
+        keys:     HashMap<Entity, I::Key>,
+        entities: BTreeMap<I::Key, Vec<Entity>>,
+    } // struct OrderedIndex
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<I: ComponentIndex> HashIndex<I> where I::Key: Hash {
+        pub(crate) fn new() -> Self {
+            HashIndex {
+                keys:     HashMap::default(),
+                entities: HashMap::default(),
+            } // HashIndex
+        } // fn new()
+    } // impl HashIndex ..
+
+
+    impl<I: ComponentIndex> OrderedIndex<I> where I::Key: Ord {
+        pub(crate) fn new() -> Self {
+            OrderedIndex {
+                keys:     HashMap::default(),
+                entities: BTreeMap::default(),
+            } // OrderedIndex
+        } // fn new()
+    } // impl OrderedIndex ..
+
+
+    impl<I: ComponentIndex> IndexStorage for HashIndex<I> where I::Key: Hash {
+        fn update(&mut self, column: &dyn ComponentColumn, entity: Entity) {
+
+            let key = read_key::<I>(column, entity);
+            if self.keys.get(&entity) == key.as_ref() { return }
+
+            self.remove_entity(entity);
+            if let Some(key) = key {
+                self.entities.entry(key.clone()).or_default().push(entity);
+                self.keys.insert(entity, key);
+            } // if ..
+
+        } // fn update()
+
+
+        fn remove_entity(&mut self, entity: Entity) {
+
+            let Some(key) = self.keys.remove(&entity) else { return };
+            if let Some(entities) = self.entities.get_mut(&key) {
+                entities.retain(|indexed| *indexed != entity);
+                if entities.is_empty() { self.entities.remove(&key); }
+            } // if ..
+
+        } // fn remove_entity()
+
+
+        fn lookup(&self, key: &dyn Any) -> Vec<Entity> {
+            key.downcast_ref::<I::Key>()
+                .and_then(|key| self.entities.get(key))
+                .cloned()
+                .unwrap_or_default()
+        } // fn lookup()
+
+
+        fn lookup_range(&self, _: Bound<&dyn Any>, _: Bound<&dyn Any>) -> Option<Vec<Entity>> { None }
+
+    } // impl IndexStorage ..
+
+
+    impl<I: ComponentIndex> IndexStorage for OrderedIndex<I> where I::Key: Ord {
+        fn update(&mut self, column: &dyn ComponentColumn, entity: Entity) {
+
+            let key = read_key::<I>(column, entity);
+            if self.keys.get(&entity) == key.as_ref() { return }
+
+            self.remove_entity(entity);
+            if let Some(key) = key {
+                self.entities.entry(key.clone()).or_default().push(entity);
+                self.keys.insert(entity, key);
+            } // if ..
+
+        } // fn update()
+
+
+        fn remove_entity(&mut self, entity: Entity) {
+
+            let Some(key) = self.keys.remove(&entity) else { return };
+            if let Some(entities) = self.entities.get_mut(&key) {
+                entities.retain(|indexed| *indexed != entity);
+                if entities.is_empty() { self.entities.remove(&key); }
+            } // if ..
+
+        } // fn remove_entity()
+
+
+        fn lookup(&self, key: &dyn Any) -> Vec<Entity> {
+            key.downcast_ref::<I::Key>()
+                .and_then(|key| self.entities.get(key))
+                .cloned()
+                .unwrap_or_default()
+        } // fn lookup()
+
+
+        fn lookup_range(&self, start: Bound<&dyn Any>, end: Bound<&dyn Any>) -> Option<Vec<Entity>> {
+            Some(self.entities
+                .range((downcast_bound::<I::Key>(start), downcast_bound::<I::Key>(end)))
+                .flat_map(|(_, entities)| entities.iter().copied())
+                .collect())
+        } // fn lookup_range()
+    } // impl IndexStorage ..
+
+
+    pub(crate) fn read_key<I: ComponentIndex>(column: &dyn ComponentColumn, entity: Entity) -> Option<I::Key> {
+        column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<I::Component>>>>()
+            .expect("Failed to downcast a component column!")
+            .get(&entity)
+            .map(|component| I::key(&component.borrow()))
+    } // fn read_key()
+
+
+    fn downcast_bound<K: 'static>(bound: Bound<&dyn Any>) -> Bound<&K> {
+        match bound {
+            Bound::Included(key) => Bound::Included(key.downcast_ref::<K>().expect("Failed to downcast an index key!")),
+            Bound::Excluded(key) => Bound::Excluded(key.downcast_ref::<K>().expect("Failed to downcast an index key!")),
+            Bound::Unbounded     => Bound::Unbounded,
+        } // match ..
+    } // fn downcast_bound()
@@ -0,0 +1,49 @@
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct MaskDescription<B, F> {
+        pub components: Vec<String>,
+        pub flags:      Vec<(F, B)>,
+        pub flag_types: Vec<(&'static str, String)>,
+    } // struct MaskDescription
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct EntityInspection<B, F> {
+        pub id:         usize,
+        pub bit_mask:   B,
+        pub mask:       MaskDescription<B, F>,
+        pub components: Vec<(String, String)>,
+    } // struct EntityInspection
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct WorldStats {
+        pub entity_count:  usize,
+        pub entity_memory: usize,
+        pub columns:       Vec<ColumnStats>,
+    } // struct WorldStats
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ColumnStats {
+        pub name:         String,
+        pub entity_count: usize,
+        pub memory_usage: usize,
+    } // struct ColumnStats
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl WorldStats {
+        pub fn memory_usage(&self) -> usize {
+            self.entity_memory + self.columns
+                .iter()
+                .map(|column| column.memory_usage)
+                .sum::<usize>()
+        } // fn memory_usage()
+    } // impl WorldStats
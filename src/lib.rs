@@ -6,7 +6,39 @@
     pub(crate) mod queries;
     pub(crate) mod entities;
     pub(crate) mod components;
+    pub(crate) mod bundles;
+    pub(crate) mod flags;
+    pub(crate) mod inspectors;
+    pub(crate) mod reflection;
+    pub(crate) mod dynamics;
+    pub(crate) mod ffi;
+    pub(crate) mod universes;
+    pub(crate) mod requirements;
+    pub(crate) mod names;
+    pub(crate) mod indexes;
+    pub(crate) mod checksums;
+    pub(crate) mod rollbacks;
+    pub(crate) mod deltas;
+    pub(crate) mod replication;
+    #[cfg(feature = "spatial")]
+    pub(crate) mod spatial;
 
-    pub use worlds::{World, WorldBuilder};
+    pub use worlds::{World, WorldBuilder, WorldError};
+    pub use universes::{Universe, WorldId};
+    pub use names::{Name, NamePolicy};
+    pub use indexes::ComponentIndex;
+    pub use checksums::StableHasher;
+    pub use rollbacks::WorldSnapshot;
+    pub use deltas::{WorldDelta, EntityMap};
+    pub use replication::{Replicate, ReplicationRule, ClientId, Transport, LoopbackTransport, ReplicationServer, ReplicationClient};
+    #[cfg(feature = "spatial")]
+    pub use spatial::{SpatialIndex, Aabb};
     pub use entities::Entity;
-    pub use components::Component;
+    pub use components::{Component, ComponentId, Ptr, Shared, SharedCloning};
+    pub use bundles::Bundle;
+    pub use flags::Flag;
+    pub use reflection::{Reflect, FieldInfo, ReflectedComponent, ReflectError};
+    pub use dynamics::{ComponentLayout, ValueKind, DynamicValue, DynamicComponent, DynamicError, AlignedBytes};
+    pub use inspectors::{MaskDescription, EntityInspection, WorldStats, ColumnStats};
+
+    pub use rusty_toolkit::BitField;
@@ -0,0 +1,106 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::HashMap;
+    use std::fmt::{self, Display};
+
+    use crate::components::Component;
+    use crate::entities::Entity;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+    pub struct Name(String);
+
+
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, Default, Debug)]
+    pub enum NamePolicy {
+        #[default]
+        Unique,
+        AllowDuplicates,
+    } // enum NamePolicy
+
+
+    pub(crate) struct NameIndex {
+        pub(crate) policy: NamePolicy,
+        entities:          HashMap<String, Vec<Entity>>,
+        names:             HashMap<Entity, String>,
+    } // struct NameIndex
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Name {
+        pub fn new(name: impl Into<String>) -> Self { Name(name.into()) }
+        pub fn as_str(&self) -> &str { &self.0 }
+    } // impl Name
+
+
+    impl Component for Name {}
+
+
+    impl Display for Name {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.0) }
+    } // impl Display ..
+
+
+    impl From<&str> for Name {
+        fn from(name: &str) -> Self { Name(name.to_string()) }
+    } // impl From ..
+
+
+    impl From<String> for Name {
+        fn from(name: String) -> Self { Name(name) }
+    } // impl From ..
+
+
+    impl NameIndex {
+        pub(crate) fn new(policy: NamePolicy) -> Self {
+            NameIndex {
+                policy,
+                entities: HashMap::default(),
+                names:    HashMap::default(),
+            } // NameIndex
+        } // fn new()
+
+
+        pub(crate) fn get(&self, name: &str) -> &[Entity] {
+            self.entities
+                .get(name)
+                .map_or(&[], Vec::as_slice)
+        } // fn get()
+
+
+        pub(crate) fn get_name(&self, entity: Entity) -> Option<&str> { self.names.get(&entity).map(String::as_str) }
+
+
+        pub(crate) fn insert(&mut self, name: &str, entity: Entity) {
+
+            if self.get_name(entity) == Some(name) { return }
+
+            self.remove_entity(entity);
+            self.entities
+                .entry(name.to_string())
+                .or_default()
+                .push(entity);
+            self.names.insert(entity, name.to_string());
+
+        } // fn insert()
+
+
+        pub(crate) fn remove_entity(&mut self, entity: Entity) {
+
+            let Some(name) = self.names.remove(&entity) else { return };
+            let Some(entities) = self.entities.get_mut(&name) else { return };
+
+            entities.retain(|named| *named != entity);
+            if entities.is_empty() { self.entities.remove(&name); }
+
+        } // fn remove_entity()
+    } // impl NameIndex
@@ -2,14 +2,20 @@
 // D E P E N D E N C I E S
 //#########################
 
+    use std::collections::BTreeMap;
     use std::fmt::Debug;
     use std::rc::Rc;
     use std::cell::RefCell;
     use std::hash::Hash;
+    use std::ops::RangeBounds;
 
     use crate::worlds::World;
-    use crate::components::Component;
+    use crate::components::{Component, ComponentId, Ptr};
     use crate::entities::Entity;
+    use crate::dynamics::DynamicComponent;
+    use crate::flags::{Flag, read_flag_index, read_flag_variant};
+    #[cfg(feature = "spatial")]
+    use crate::spatial::{SpatialIndex, Aabb};
 
     use rusty_toolkit::BitField;
 
@@ -25,11 +31,16 @@
 
 
     pub struct QueryBuilder<'world, B: BitField, F: BitField, P: Hash + Eq + Debug> {
-        pub(crate) bit_mask: B,
-        pub(crate) world:    &'world World<B, F, P>,
+        pub(crate) bit_mask:     B,
+        pub(crate) flag_filters: Vec<FlagFilter<'world, B>>,
+        pub(crate) candidates:   Option<Vec<Entity>>,
+        pub(crate) world:        &'world World<B, F, P>,
     } // struct QueryBuilder
 
 
+    pub(crate) type FlagFilter<'world, B> = Box<dyn Fn(B) -> bool + 'world>;
+
+
 //###############################
 // I M P L E M E N T A T I O N S
 //###############################
@@ -38,6 +49,7 @@
         pub fn get_components<C: Component>(&self) -> Vec<&Rc<RefCell<C>>> {
 
             let component_column = self.world.get_component_column::<C>();
+            self.world.mark_indexes_dirty(self.world.component_id::<C>().index() as usize, &self.entities);
             self.entities
                 .iter()
                 .map(|entity| component_column
@@ -48,8 +60,103 @@
         } // fn get_components()
 
 
+        pub fn get_dynamic_components(&self, id: ComponentId) -> Vec<&DynamicComponent> {
+
+            let component_column = self.world
+                .get_dynamic_column(id)
+                .unwrap_or_else(|error| panic!("{}", error));
+
+            self.entities
+                .iter()
+                .map(|entity| component_column.values
+                    .get(entity)
+                    .expect("Attempted to find a component with an entity ID that was not registered in the column!"))
+                .collect()
+
+        } // fn get_dynamic_components()
+
+
+        pub fn get_components_by_id(&self, id: ComponentId) -> Vec<Ptr<'world>> {
+            self.entities
+                .iter()
+                .map(|entity| self.world
+                    .get_by_id(*entity, id)
+                    .expect("Attempted to find a component with an entity ID that was not registered in the column!"))
+                .collect()
+        } // fn get_components_by_id()
+
+
+        pub fn get_rows_by_id(&self, ids: &[ComponentId]) -> Vec<(Entity, Vec<Ptr<'world>>)> {
+            self.entities
+                .iter()
+                .map(|entity| (*entity, ids
+                    .iter()
+                    .map(|id| self.world
+                        .get_by_id(*entity, *id)
+                        .expect("Attempted to find a component with an entity ID that was not registered in the column!"))
+                    .collect()))
+                .collect()
+        } // fn get_rows_by_id()
+
+
         pub fn get_entities(&self) -> Vec<Entity> { self.entities.clone() }
 
+
+        pub fn sort_by_key<C: Component, K: Ord>(mut self, key: impl Fn(&C) -> K) -> Self {
+
+            let component_column = self.world.get_component_column::<C>();
+            self.entities.sort_by_cached_key(|entity| match component_column.get(entity) {
+                Some(component) => (false, Some(key(&component.borrow()))),
+                None            => (true, None),
+            }); // sort_by_cached_key()
+
+            self
+
+        } // fn sort_by_key()
+
+
+        pub fn group_by<C: Component, K: Ord>(&self, key: impl Fn(&C) -> K) -> Vec<(Option<K>, Vec<Entity>)> {
+
+            let component_column = self.world.get_component_column::<C>();
+            let mut groups       = self.entities
+                .iter()
+                .fold(BTreeMap::new(), |mut groups: BTreeMap<Option<K>, Vec<Entity>>, entity| {
+
+                    groups.entry(component_column
+                        .get(entity)
+                        .map(|component| key(&component.borrow())))
+                        .or_default()
+                        .push(*entity);
+                    groups
+
+                }).into_iter()
+                .collect::<Vec<(Option<K>, Vec<Entity>)>>();
+
+            if groups.first().is_some_and(|(key, _)| key.is_none()) { groups.rotate_left(1usize) }
+            groups
+
+        } // fn group_by()
+
+
+        pub fn group_by_shared<C: Component>(&self) -> Vec<(&'world Rc<RefCell<C>>, Vec<Entity>)> {
+
+            let component_column = self.world.get_component_column::<C>();
+            self.world.mark_indexes_dirty(self.world.component_id::<C>().index() as usize, &self.entities);
+            self.entities
+                .iter()
+                .fold(Vec::new(), |mut groups: Vec<(&'world Rc<RefCell<C>>, Vec<Entity>)>, entity| {
+
+                    let Some(component) = component_column.get(entity) else { return groups };
+                    match groups.iter_mut().find(|(shared, _)| Rc::ptr_eq(shared, component)) {
+                        Some((_, group)) => group.push(*entity),
+                        None             => groups.push((component, vec![*entity])),
+                    } // match ..
+
+                    groups
+
+                }) // fold()
+        } // fn group_by_shared()
+
     } // impl Query
 
 
@@ -62,6 +169,14 @@
         } // fn with_component()
 
 
+        pub fn with_component_id(mut self, id: ComponentId) -> Self {
+
+            self.bit_mask |= B::bit(id.0);
+            self
+
+        } // fn with_component_id()
+
+
         pub fn with_flag<T: Into<F>>(mut self, flag: T, variant: Option<B>) -> Self {
 
             self.bit_mask |= self.world.flag_bit_mask(flag.into(), variant);
@@ -70,9 +185,127 @@
         } // fn with_flag()
 
 
+        pub fn with_flag_unset<T: Into<F>>(mut self, flag: T) -> Self {
+
+            let range = self.world.flag_range(flag.into());
+            self.flag_filters.push(Box::new(move |bit_mask| read_flag_variant(bit_mask, range.clone()) == B::MIN));
+            self
+
+        } // fn with_flag_unset()
+
+
+        pub fn with_flag_variants<T: Into<F>>(mut self, flag: T, variants: &[B]) -> Self {
+
+            let range    = self.world.flag_range(flag.into());
+            let variants = variants.to_vec();
+            self.flag_filters.push(Box::new(move |bit_mask| variants.contains(&read_flag_variant(bit_mask, range.clone()))));
+            self
+
+        } // fn with_flag_variants()
+
+
+        pub fn with_flag_range<T: Into<F>, R: RangeBounds<B> + 'world>(mut self, flag: T, variants: R) -> Self
+        where B: PartialOrd {
+
+            let range = self.world.flag_range(flag.into());
+            self.flag_filters.push(Box::new(move |bit_mask| variants.contains(&read_flag_variant(bit_mask, range.clone()))));
+            self
+
+        } // fn with_flag_range()
+
+
+        pub fn with_flag_matching<T: Into<F>>(mut self, flag: T, predicate: impl Fn(Option<B>) -> bool + 'world) -> Self {
+
+            let range = self.world.flag_range(flag.into());
+            self.flag_filters.push(Box::new(move |bit_mask| {
+                let variant = read_flag_variant(bit_mask, range.clone());
+                predicate(match variant == B::MIN {
+                    true  => None,
+                    false => Some(variant),
+                }) // predicate()
+            })); // push()
+
+            self
+
+        } // fn with_flag_matching()
+
+
+        pub fn with_typed_flag<T: Flag>(self, flag: T) -> Self { self.with_typed_flag_matching(move |variant: T| variant == flag) }
+
+
+        pub fn with_typed_flag_in<T: Flag>(self, flags: &[T]) -> Self {
+
+            let flags = flags.to_vec();
+            self.with_typed_flag_matching(move |variant: T| flags.contains(&variant))
+
+        } // fn with_typed_flag_in()
+
+
+        pub fn with_typed_flag_matching<T: Flag>(mut self, predicate: impl Fn(T) -> bool + 'world) -> Self {
+
+            let range = self.world.flag_type_range::<T>();
+            self.flag_filters.push(Box::new(move |bit_mask| T::from_index(read_flag_index(bit_mask, range.clone()))
+                .is_some_and(&predicate)));
+
+            self
+
+        } // fn with_typed_flag_matching()
+
+
+        #[cfg(feature = "spatial")]
+        pub fn within_aabb<S: SpatialIndex>(self, aabb: Aabb) -> Self {
+
+            let entities = self.world.query_aabb::<S>(aabb);
+            self.with_candidates(entities)
+
+        } // fn within_aabb()
+
+
+        #[cfg(feature = "spatial")]
+        pub fn within_radius<S: SpatialIndex>(self, center: [f32; 2], radius: f32) -> Self {
+
+            let entities = self.world.query_radius::<S>(center, radius);
+            self.with_candidates(entities)
+
+        } // fn within_radius()
+
+
+        #[cfg(feature = "spatial")]
+        fn with_candidates(mut self, entities: Vec<Entity>) -> Self {
+
+            self.candidates = Some(match self.candidates {
+                Some(candidates) => entities
+                    .into_iter()
+                    .filter(|entity| candidates.contains(entity))
+                    .collect(),
+                None => entities,
+            }); // Some()
+
+            self
+
+        } // fn with_candidates()
+
+
         pub fn build(self) -> Query<'world, B, F, P> {
 
-            let entities = self.world.get_entities(self.bit_mask);
+            let entities = match self.candidates {
+                Some(candidates) => candidates
+                    .into_iter()
+                    .filter(|entity| self.world.get_entity_bit_mask(*entity) & self.bit_mask == self.bit_mask)
+                    .collect(),
+                None => self.world.get_entities(self.bit_mask),
+            }; // let entities
+
+            let mut entities = entities
+                .into_iter()
+                .filter(|entity| {
+                    let bit_mask = self.world.get_entity_bit_mask(*entity);
+                    self.flag_filters
+                        .iter()
+                        .all(|filter| filter(bit_mask))
+                }).collect::<Vec<Entity>>();
+
+            entities.sort_by_key(Entity::id);
 
             Query {
                 entities,
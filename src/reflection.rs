@@ -0,0 +1,222 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::HashMap;
+    use std::any::Any;
+    use std::rc::Rc;
+    use std::cell::{Ref, RefCell, RefMut};
+    use std::fmt::{self, Display};
+    use std::error::Error;
+
+    use crate::components::{Component, ComponentColumn};
+    use crate::entities::Entity;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub trait Reflect: Any {
+        fn type_name(&self) -> &'static str;
+        fn field_infos() -> Vec<FieldInfo> where Self: Sized;
+        fn fields(&self) -> Vec<FieldInfo>;
+        fn field(&self, name: &str) -> Option<&dyn Reflect>;
+        fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect>;
+        fn set(&mut self, value: &dyn Reflect) -> Result<(), ReflectError>;
+        fn as_any(&self) -> &dyn Any;
+        fn as_any_mut(&mut self) -> &mut dyn Any;
+    } // trait Reflect
+
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldInfo {
+        pub name:      &'static str,
+        pub type_name: &'static str,
+    } // struct FieldInfo
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ReflectedComponent {
+        pub name:   String,
+        pub fields: Vec<FieldInfo>,
+    } // struct ReflectedComponent
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum ReflectError {
+        UnknownPath(String),
+        TypeMismatch {
+            expected: &'static str,
+            found:    &'static str,
+        }, // TypeMismatch
+    } // enum ReflectError
+
+
+    #[derive(Clone)]
+    pub(crate) struct ReflectInfo {
+        pub(crate) fields:  Vec<FieldInfo>,
+        pub(crate) get:     ReflectGetter,
+        pub(crate) get_mut: ReflectGetterMut,
+    } // struct ReflectInfo
+
+
+    pub(crate) type ReflectGetter    = for<'column> fn(&'column dyn ComponentColumn, Entity) -> Option<Ref<'column, dyn Reflect>>;
+    pub(crate) type ReflectGetterMut = for<'column> fn(&'column dyn ComponentColumn, Entity) -> Option<RefMut<'column, dyn Reflect>>;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl dyn Reflect {
+        pub fn path(&self, path: &str) -> Option<&dyn Reflect> {
+            path.split('.')
+                .filter(|field| !field.is_empty())
+                .try_fold(self, |value, field| value.field(field))
+        } // fn path()
+
+
+        pub fn path_mut(&mut self, path: &str) -> Option<&mut dyn Reflect> {
+            path.split('.')
+                .filter(|field| !field.is_empty())
+                .try_fold(self, |value, field| value.field_mut(field))
+        } // fn path_mut()
+
+
+        pub fn set_path(&mut self, path: &str, value: &dyn Reflect) -> Result<(), ReflectError> {
+            self.path_mut(path)
+                .ok_or_else(|| ReflectError::UnknownPath(path.to_string()))?
+                .set(value)
+        } // fn set_path()
+
+
+        pub fn downcast_ref<T: Reflect>(&self) -> Option<&T> { self.as_any().downcast_ref::<T>() }
+        pub fn downcast_mut<T: Reflect>(&mut self) -> Option<&mut T> { self.as_any_mut().downcast_mut::<T>() }
+
+    } // impl dyn Reflect
+
+
+    impl Display for ReflectError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ReflectError::UnknownPath(path)                => write!(f, "No field was found at path `{}`!", path),
+                ReflectError::TypeMismatch { expected, found } => write!(f, "Expected a value of type {} but found {}!", expected, found),
+            } // match ..
+        } // fn fmt()
+    } // impl Display ..
+
+
+    impl Error for ReflectError {}
+
+
+    impl ReflectInfo {
+        pub(crate) fn of<C: Component + Reflect>() -> Self {
+            ReflectInfo {
+                fields:  C::field_infos(),
+                get:     reflect_component::<C>,
+                get_mut: reflect_component_mut::<C>,
+            } // ReflectInfo
+        } // fn of()
+    } // impl ReflectInfo
+
+
+    fn reflect_component<C: Component + Reflect>(column: &dyn ComponentColumn, entity: Entity) -> Option<Ref<'_, dyn Reflect>> {
+        column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()?
+            .get(&entity)
+            .map(|component| Ref::map(component.borrow(), |component| component as &dyn Reflect))
+    } // fn reflect_component()
+
+
+    fn reflect_component_mut<C: Component + Reflect>(column: &dyn ComponentColumn, entity: Entity) -> Option<RefMut<'_, dyn Reflect>> {
+        column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()?
+            .get(&entity)
+            .map(|component| RefMut::map(component.borrow_mut(), |component| component as &mut dyn Reflect))
+    } // fn reflect_component_mut()
+
+
+    #[macro_export]
+    macro_rules! impl_reflect {
+        ($reflect:ident { $($field:ident: $kind:ty),* $(,)? }) => {
+            impl $crate::Reflect for $reflect {
+                fn type_name(&self) -> &'static str { ::std::any::type_name::<Self>() }
+
+
+                fn field_infos() -> Vec<$crate::FieldInfo> {
+                    vec![$($crate::FieldInfo {
+                        name:      stringify!($field),
+                        type_name: ::std::any::type_name::<$kind>(),
+                    }),*] // vec!
+                } // fn field_infos()
+
+
+                fn fields(&self) -> Vec<$crate::FieldInfo> { <Self as $crate::Reflect>::field_infos() }
+
+
+                fn field(&self, name: &str) -> Option<&dyn $crate::Reflect> {
+                    match name {
+                        $(stringify!($field) => Some(&self.$field),)*
+                        _ => None,
+                    } // match ..
+                } // fn field()
+
+
+                fn field_mut(&mut self, name: &str) -> Option<&mut dyn $crate::Reflect> {
+                    match name {
+                        $(stringify!($field) => Some(&mut self.$field),)*
+                        _ => None,
+                    } // match ..
+                } // fn field_mut()
+
+
+                fn set(&mut self, value: &dyn $crate::Reflect) -> Result<(), $crate::ReflectError> {
+                    match value.as_any().downcast_ref::<Self>() {
+                        Some(value) => { self.clone_from(value); Ok(()) },
+                        None        => Err($crate::ReflectError::TypeMismatch {
+                            expected: ::std::any::type_name::<Self>(),
+                            found:    value.type_name(),
+                        }), // => ..
+                    } // match ..
+                } // fn set()
+
+
+                fn as_any(&self)         -> &dyn ::std::any::Any     { self }
+                fn as_any_mut(&mut self) -> &mut dyn ::std::any::Any { self }
+            } // impl Reflect ..
+        }; // =>
+    } // macro_rules! impl_reflect
+
+
+    macro_rules! impl_value_reflect {
+        ($($value:ident),+) => {$(
+            impl Reflect for $value {
+                fn type_name(&self) -> &'static str { std::any::type_name::<Self>() }
+                fn field_infos() -> Vec<FieldInfo>  { Vec::new() }
+                fn fields(&self) -> Vec<FieldInfo>  { Vec::new() }
+
+                fn field(&self, _: &str) -> Option<&dyn Reflect>         { None }
+                fn field_mut(&mut self, _: &str) -> Option<&mut dyn Reflect> { None }
+
+                fn set(&mut self, value: &dyn Reflect) -> Result<(), ReflectError> {
+                    match value.as_any().downcast_ref::<Self>() {
+                        Some(value) => { self.clone_from(value); Ok(()) },
+                        None        => Err(ReflectError::TypeMismatch {
+                            expected: std::any::type_name::<Self>(),
+                            found:    value.type_name(),
+                        }), // => ..
+                    } // match ..
+                } // fn set()
+
+
+                fn as_any(&self)         -> &dyn Any     { self }
+                fn as_any_mut(&mut self) -> &mut dyn Any { self }
+            } // impl Reflect ..
+        )+}; // =>
+    } // macro_rules! impl_value_reflect
+
+
+    impl_value_reflect!(bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, String);
@@ -0,0 +1,264 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, BTreeMap, VecDeque};
+    use std::hash::Hash;
+    use std::fmt::Debug;
+
+    use crate::worlds::{World, WorldError};
+    use crate::components::Component;
+    use crate::entities::Entity;
+    use crate::rollbacks::WorldSnapshot;
+    use crate::deltas::{WorldDelta, EntityMap};
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, Default, Debug)]
+    pub enum ReplicationRule {
+        Always,
+        #[default]
+        OnChange,
+        OwnerOnly,
+    } // enum ReplicationRule
+
+
+    pub trait Replicate: Component + PartialEq {
+        fn encode(&self, bytes: &mut Vec<u8>);
+        fn decode(bytes: &[u8]) -> Option<Self>;
+    } // trait Replicate
+
+
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+    pub struct ClientId(usize);
+
+
+    pub trait Transport<B: BitField> {
+        fn send(&mut self, client: ClientId, delta: WorldDelta<B>);
+        fn receive(&mut self, client: ClientId) -> Option<WorldDelta<B>>;
+    } // trait Transport
+
+
+    pub struct LoopbackTransport<B: BitField> {
+        queues: HashMap<ClientId, VecDeque<WorldDelta<B>>>,
+    } // struct LoopbackTransport
+
+
+    pub struct ReplicationServer<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        clients:        BTreeMap<ClientId, ClientState<B, F, P>>,
+        owners:         HashMap<Entity, ClientId>,
+        previous:       WorldSnapshot<B>,
+        next_client_id: usize,
+    } // struct ReplicationServer
+
+
+    pub struct ReplicationClient {
+        id:         ClientId,
+        entity_map: EntityMap,
+        desynced:   bool,
+    } // struct ReplicationClient
+
+
+    struct ClientState<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        interest: Option<InterestFn<B, F, P>>,
+        known:    BTreeMap<Entity, B>,
+        resync:   bool,
+    } // struct ClientState
+
+
+    type InterestFn<B, F, P> = Box<dyn Fn(&World<B, F, P>, Entity) -> bool>;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl ClientId {
+        pub const fn new(id: usize) -> Self { ClientId(id) }
+        pub const fn id(&self) -> usize { self.0 }
+    } // impl ClientId
+
+
+    impl<B: BitField> Default for LoopbackTransport<B> {
+        fn default() -> Self {
+            LoopbackTransport {
+                queues: HashMap::default(),
+            } // LoopbackTransport
+        } // fn default()
+    } // impl Default ..
+
+
+    impl<B: BitField> LoopbackTransport<B> {
+        pub fn new() -> Self { Self::default() }
+        pub fn pending_count(&self, client: ClientId) -> usize { self.queues.get(&client).map_or(0usize, VecDeque::len) }
+    } // impl LoopbackTransport ..
+
+
+    impl<B: BitField> Transport<B> for LoopbackTransport<B> {
+        fn send(&mut self, client: ClientId, delta: WorldDelta<B>) { self.queues.entry(client).or_default().push_back(delta) }
+        fn receive(&mut self, client: ClientId) -> Option<WorldDelta<B>> { self.queues.get_mut(&client)?.pop_front() }
+    } // impl Transport ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Default for ReplicationServer<B, F, P> {
+        fn default() -> Self {
+            ReplicationServer {
+                clients:        BTreeMap::default(),
+                owners:         HashMap::default(),
+                previous:       WorldSnapshot::empty(),
+                next_client_id: 0usize,
+            } // ReplicationServer
+        } // fn default()
+    } // impl Default ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> ReplicationServer<B, F, P> {
+        pub fn new() -> Self { Self::default() }
+
+
+        pub fn add_client(&mut self) -> ClientId {
+
+            let id = ClientId(self.next_client_id);
+            self.next_client_id += 1;
+            self.clients.insert(id, ClientState {
+                interest: None,
+                known:    BTreeMap::default(),
+                resync:   false,
+            }); // insert()
+
+            id
+
+        } // fn add_client()
+
+
+        pub fn remove_client(&mut self, client: ClientId) {
+
+            self.clients.remove(&client);
+            self.owners.retain(|_, owner| *owner != client);
+
+        } // fn remove_client()
+
+
+        pub fn get_client_ids(&self) -> Vec<ClientId> { self.clients.keys().copied().collect() }
+
+
+        pub fn set_interest(&mut self, client: ClientId, interest: impl Fn(&World<B, F, P>, Entity) -> bool + 'static) {
+            self.clients
+                .get_mut(&client)
+                .expect("Attempted to find a client that was not registered!")
+                .interest = Some(Box::new(interest));
+        } // fn set_interest()
+
+
+        pub fn clear_interest(&mut self, client: ClientId) {
+            self.clients
+                .get_mut(&client)
+                .expect("Attempted to find a client that was not registered!")
+                .interest = None;
+        } // fn clear_interest()
+
+
+        pub fn resync_client(&mut self, client: ClientId) {
+
+            let client = self.clients
+                .get_mut(&client)
+                .expect("Attempted to find a client that was not registered!");
+
+            client.known.clear();
+            client.resync = true;
+
+        } // fn resync_client()
+
+
+        pub fn set_owner(&mut self, entity: Entity, client: ClientId) {
+
+            if !self.clients.contains_key(&client) { panic!("Attempted to find a client that was not registered!") }
+            self.owners.insert(entity, client);
+
+        } // fn set_owner()
+
+
+        pub fn clear_owner(&mut self, entity: Entity) { self.owners.remove(&entity); }
+        pub fn get_owner(&self, entity: Entity) -> Option<ClientId> { self.owners.get(&entity).copied() }
+
+
+        pub fn update(&mut self, world: &World<B, F, P>, transport: &mut impl Transport<B>) {
+
+            self.owners.retain(|entity, _| world.contains_entity(*entity));
+
+            let owners  = &self.owners;
+            let changes = world.diff_columns(&self.previous);
+            self.clients
+                .iter_mut()
+                .for_each(|(id, client)| {
+
+                    let mut delta = world.diff_with(
+                        &changes,
+                        &client.known,
+                        |entity| client.interest
+                            .as_ref()
+                            .is_none_or(|interest| interest(world, entity)),
+                        |entity| owners.get(&entity) == Some(id),
+                    ); // let delta
+
+                    delta.full = std::mem::take(&mut client.resync);
+                    if delta.is_empty() && !delta.full { return }
+
+                    delta.apply_to_known(&mut client.known);
+                    transport.send(*id, delta);
+
+                }); // for_each()
+
+            self.previous = world.snapshot_from(&self.previous);
+
+        } // fn update()
+    } // impl ReplicationServer ..
+
+
+    impl ReplicationClient {
+        pub fn new(id: ClientId) -> Self {
+            ReplicationClient {
+                id,
+                entity_map: EntityMap::default(),
+                desynced:   false,
+            } // ReplicationClient
+        } // fn new()
+
+
+        pub fn id(&self) -> ClientId { self.id }
+        pub fn get_entity_map(&self) -> &EntityMap { &self.entity_map }
+        pub fn is_desynced(&self) -> bool { self.desynced }
+        pub fn get_local_entity(&self, entity: Entity) -> Option<Entity> { self.entity_map.get_local_entity(entity) }
+        pub fn get_server_entity(&self, entity: Entity) -> Option<Entity> { self.entity_map.get_server_entity(entity) }
+
+
+        pub fn receive<B: BitField, F: BitField, P: Hash + Eq + Debug>(
+            &mut self,
+            world:     &mut World<B, F, P>,
+            transport: &mut impl Transport<B>,
+        ) -> Result<usize, WorldError> {
+
+            let mut count = 0usize;
+            while let Some(delta) = transport.receive(self.id) {
+
+                if self.desynced && !delta.full { continue }
+
+                if let Err(error) = world.apply_delta(&delta, &mut self.entity_map) {
+                    self.desynced = true;
+                    return Err(error)
+                } // if ..
+
+                self.desynced = false;
+                count        += 1;
+
+            } // while ..
+
+            Ok(count)
+
+        } // fn receive()
+    } // impl ReplicationClient
@@ -0,0 +1,51 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt::Debug;
+    use std::hash::Hash;
+
+    use crate::worlds::World;
+    use crate::bundles::Bundle;
+    use crate::entities::Entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub(crate) struct Requirement<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        pub(crate) bit_mask: fn(&World<B, F, P>) -> B,
+        pub(crate) insert:   fn(&mut World<B, F, P>, Entity, B) -> B,
+    } // struct Requirement
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Requirement<B, F, P> {
+        pub(crate) fn of<R: Bundle + Default>() -> Self {
+            Requirement {
+                bit_mask: R::bit_mask::<B, F, P>,
+                insert:   insert_required::<R, B, F, P>,
+            } // Requirement
+        } // fn of()
+    } // impl Requirement ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Clone for Requirement<B, F, P> {
+        fn clone(&self) -> Self { *self }
+    } // impl Clone ..
+
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Copy for Requirement<B, F, P> {}
+
+
+    fn insert_required<R: Bundle + Default, B: BitField, F: BitField, P: Hash + Eq + Debug>(
+        world:    &mut World<B, F, P>,
+        entity:   Entity,
+        bit_mask: B,
+    ) -> B { R::default().insert_missing_components(world, entity, bit_mask) }
@@ -0,0 +1,122 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, HashSet, BTreeMap};
+    use std::any::Any;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+
+    use crate::components::{Component, ComponentColumn};
+    use crate::entities::{Entity, EntityId};
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone)]
+    pub struct WorldSnapshot<B: BitField> {
+        pub(crate) entities:       BTreeMap<Entity, B>,
+        pub(crate) next_entity_id: EntityId,
+        pub(crate) columns:        Vec<(usize, Rc<dyn ColumnSnapshot>)>,
+    } // struct WorldSnapshot
+
+
+    pub(crate) trait ColumnSnapshot {
+        fn as_any(&self) -> &dyn Any;
+        fn restore(&self, column: &mut dyn ComponentColumn);
+    } // trait ColumnSnapshot
+
+
+    pub(crate) struct RollbackColumn<C: Component>(pub(crate) HashMap<Entity, Rc<C>>);
+
+
+    pub(crate) type SnapshotFn = fn(&dyn ComponentColumn, Option<&dyn ColumnSnapshot>) -> Rc<dyn ColumnSnapshot>;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField> WorldSnapshot<B> {
+        pub fn empty() -> Self {
+            WorldSnapshot {
+                entities:       BTreeMap::default(),
+                next_entity_id: 0usize,
+                columns:        Vec::new(),
+            } // WorldSnapshot
+        } // fn empty()
+
+
+        pub fn entity_count(&self) -> usize { self.entities.len() }
+        pub fn contains_entity(&self, entity: Entity) -> bool { self.entities.contains_key(&entity) }
+    } // impl WorldSnapshot ..
+
+
+    impl<C: Component> ColumnSnapshot for RollbackColumn<C> {
+        fn as_any(&self) -> &dyn Any { self }
+
+
+        fn restore(&self, column: &mut dyn ComponentColumn) {
+
+            let column = column
+                .as_any_mut()
+                .downcast_mut::<HashMap<Entity, Rc<RefCell<C>>>>()
+                .expect("Failed to downcast a component column!");
+
+            let live         = std::mem::take(column);
+            let mut claimed  = HashSet::new();
+            let mut cells    = HashMap::<*const C, Rc<RefCell<C>>>::new();
+            let mut entities = self.0.keys().copied().collect::<Vec<Entity>>();
+            entities.sort();
+
+            entities
+                .iter()
+                .for_each(|entity| {
+
+                    let (Some(component), Some(cell)) = (self.0.get(entity), live.get(entity)) else { return };
+                    if cells.contains_key(&Rc::as_ptr(component)) || !claimed.insert(Rc::as_ptr(cell)) { return }
+
+                    *cell.borrow_mut() = C::clone(component);
+                    cells.insert(Rc::as_ptr(component), cell.clone());
+
+                }); // for_each()
+
+            column.extend(self.0
+                .iter()
+                .map(|(entity, component)| (*entity, cells
+                    .entry(Rc::as_ptr(component))
+                    .or_insert_with(|| Rc::new(RefCell::new(C::clone(component))))
+                    .clone())));
+
+        } // fn restore()
+    } // impl ColumnSnapshot ..
+
+
+    pub(crate) fn snapshot_column<C: Component + PartialEq>(column: &dyn ComponentColumn, base: Option<&dyn ColumnSnapshot>) -> Rc<dyn ColumnSnapshot> {
+
+        let base   = base.and_then(|base| base.as_any().downcast_ref::<RollbackColumn<C>>());
+        let column = column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()
+            .expect("Failed to downcast a component column!");
+
+        let mut reused = HashSet::new();
+        let mut shared = HashMap::<*const RefCell<C>, Rc<C>>::new();
+        Rc::new(RollbackColumn(column
+            .iter()
+            .map(|(entity, cell)| (*entity, shared
+                .entry(Rc::as_ptr(cell))
+                .or_insert_with(|| {
+                    let component = cell.borrow();
+                    match base.and_then(|base| base.0.get(entity)) {
+                        Some(unchanged) if **unchanged == *component && reused.insert(Rc::as_ptr(unchanged)) => unchanged.clone(),
+                        _                                                                                    => Rc::new(component.clone()),
+                    } // match ..
+                }).clone()))
+            .collect()))
+
+    } // fn snapshot_column()
@@ -0,0 +1,339 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, BTreeMap};
+    use std::any::Any;
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::marker::PhantomData;
+    use std::ops::Bound;
+
+    use crate::components::{Component, ComponentColumn};
+    use crate::indexes::IndexStorage;
+    use crate::entities::Entity;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub trait SpatialIndex: 'static {
+        type Component: Component;
+
+        fn position(component: &Self::Component) -> [f32; 2];
+
+    } // trait SpatialIndex
+
+
+    #[derive(Clone, Copy, PartialEq, Default, Debug)]
+    pub struct Aabb {
+        pub min: [f32; 2],
+        pub max: [f32; 2],
+    } // struct Aabb
+
+
+    pub(crate) struct GridIndex<S: SpatialIndex> {
+        cell_size: f32,
+        positions: HashMap<Entity, [f32; 2]>,
+        cells:     BTreeMap<(i64, i64), Vec<Entity>>,
+        index:     PhantomData<S>,
+    } // struct GridIndex
+
+
+    pub(crate) struct QuadTreeIndex<S: SpatialIndex> {
+        positions: HashMap<Entity, [f32; 2]>,
+        root:      QuadNode,
+        outside:   Vec<Entity>,
+        capacity:  usize,
+        index:     PhantomData<S>,
+    } // struct QuadTreeIndex
+
+
+    struct QuadNode {
+        bounds:   Aabb,
+        entities: Vec<(Entity, [f32; 2])>,
+        children: Option<Box<[QuadNode; 4]>>,
+    } // struct QuadNode
+
+
+    type CellIter<'index> = Box<dyn Iterator<Item = (&'index (i64, i64), &'index Vec<Entity>)> + 'index>;
+
+
+    const MAX_QUAD_DEPTH: usize = 16usize;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Aabb {
+        pub const fn new(min: [f32; 2], max: [f32; 2]) -> Self { Aabb { min, max } }
+
+
+        pub fn around(center: [f32; 2], radius: f32) -> Self {
+            Aabb {
+                min: [center[0] - radius, center[1] - radius],
+                max: [center[0] + radius, center[1] + radius],
+            } // Aabb
+        } // fn around()
+
+
+        pub fn contains(&self, point: [f32; 2]) -> bool {
+            (0..2).all(|axis| self.min[axis] <= point[axis] && point[axis] <= self.max[axis])
+        } // fn contains()
+
+
+        pub fn intersects(&self, other: &Aabb) -> bool {
+            (0..2).all(|axis| self.min[axis] <= other.max[axis] && other.min[axis] <= self.max[axis])
+        } // fn intersects()
+
+
+        fn center(&self) -> [f32; 2] { [(self.min[0] + self.max[0]) * 0.5f32, (self.min[1] + self.max[1]) * 0.5f32] }
+
+
+        fn quadrant(&self, point: [f32; 2]) -> usize {
+            let center = self.center();
+            usize::from(point[0] >= center[0]) + 2usize * usize::from(point[1] >= center[1])
+        } // fn quadrant()
+
+
+        fn quadrants(&self) -> [Aabb; 4] {
+            let center = self.center();
+            [
+                Aabb::new(self.min, center),
+                Aabb::new([center[0], self.min[1]], [self.max[0], center[1]]),
+                Aabb::new([self.min[0], center[1]], [center[0], self.max[1]]),
+                Aabb::new(center, self.max),
+            ] // [..]
+        } // fn quadrants()
+    } // impl Aabb
+
+
+    impl<S: SpatialIndex> GridIndex<S> {
+        pub(crate) fn new(cell_size: f32) -> Self {
+
+            assert!(cell_size > 0f32, "WARNING: spatial grid cell size must be positive!");
+            GridIndex {
+                cell_size,
+                positions: HashMap::default(),
+                cells:     BTreeMap::default(),
+                index:     PhantomData,
+            } // GridIndex
+
+        } // fn new()
+
+
+        fn cell(&self, point: [f32; 2]) -> (i64, i64) {
+            (
+                (point[0] / self.cell_size).floor() as i64,
+                (point[1] / self.cell_size).floor() as i64,
+            ) // (..)
+        } // fn cell()
+    } // impl GridIndex ..
+
+
+    impl<S: SpatialIndex> IndexStorage for GridIndex<S> {
+        fn update(&mut self, column: &dyn ComponentColumn, entity: Entity) {
+
+            let position = read_position::<S>(column, entity);
+            if self.positions.get(&entity) == position.as_ref() { return }
+
+            self.remove_entity(entity);
+            if let Some(position) = position {
+                self.cells.entry(self.cell(position)).or_default().push(entity);
+                self.positions.insert(entity, position);
+            } // if ..
+
+        } // fn update()
+
+
+        fn remove_entity(&mut self, entity: Entity) {
+
+            let Some(position) = self.positions.remove(&entity) else { return };
+            let cell           = self.cell(position);
+            if let Some(entities) = self.cells.get_mut(&cell) {
+                entities.retain(|indexed| *indexed != entity);
+                if entities.is_empty() { self.cells.remove(&cell); }
+            } // if ..
+
+        } // fn remove_entity()
+
+
+        fn lookup(&self, key: &dyn Any) -> Vec<Entity> {
+
+            let Some(aabb) = key.downcast_ref::<Aabb>() else { return Vec::new() };
+            let (min, max) = (self.cell(aabb.min), self.cell(aabb.max));
+            if min.0 > max.0 || min.1 > max.1 { return Vec::new() }
+
+            let cells: CellIter<'_> = match max.0.abs_diff(min.0) < self.cells.len() as u64 {
+                true  => Box::new((min.0..=max.0).flat_map(|x| self.cells.range((x, min.1)..=(x, max.1)))),
+                false => Box::new(self.cells
+                    .range(min..=max)
+                    .filter(|((_, y), _)| (min.1..=max.1).contains(y))),
+            }; // let cells
+
+            let mut entities = cells
+                .flat_map(|(_, entities)| entities.iter().copied())
+                .filter(|entity| aabb.contains(self.positions[entity]))
+                .collect::<Vec<Entity>>();
+
+            entities.sort_by_key(Entity::id);
+            entities
+
+        } // fn lookup()
+
+
+        fn lookup_range(&self, _: Bound<&dyn Any>, _: Bound<&dyn Any>) -> Option<Vec<Entity>> { None }
+
+    } // impl IndexStorage ..
+
+
+    impl<S: SpatialIndex> QuadTreeIndex<S> {
+        pub(crate) fn new(bounds: Aabb, capacity: usize) -> Self {
+            QuadTreeIndex {
+                positions: HashMap::default(),
+                root:      QuadNode::new(bounds),
+                outside:   Vec::new(),
+                capacity:  capacity.max(1usize),
+                index:     PhantomData,
+            } // QuadTreeIndex
+        } // fn new()
+    } // impl QuadTreeIndex ..
+
+
+    impl<S: SpatialIndex> IndexStorage for QuadTreeIndex<S> {
+        fn update(&mut self, column: &dyn ComponentColumn, entity: Entity) {
+
+            let position = read_position::<S>(column, entity);
+            if self.positions.get(&entity) == position.as_ref() { return }
+
+            self.remove_entity(entity);
+            if let Some(position) = position {
+                match self.root.bounds.contains(position) {
+                    true  => self.root.insert(entity, position, self.capacity, 0usize),
+                    false => self.outside.push(entity),
+                } // match ..
+                self.positions.insert(entity, position);
+            } // if ..
+
+        } // fn update()
+
+
+        fn remove_entity(&mut self, entity: Entity) {
+
+            let Some(position) = self.positions.remove(&entity) else { return };
+            match self.root.bounds.contains(position) {
+                true  => self.root.remove(entity, position, self.capacity),
+                false => self.outside.retain(|outside| *outside != entity),
+            } // match ..
+
+        } // fn remove_entity()
+
+
+        fn lookup(&self, key: &dyn Any) -> Vec<Entity> {
+
+            let Some(aabb) = key.downcast_ref::<Aabb>() else { return Vec::new() };
+
+            let mut entities = Vec::new();
+            self.root.query(aabb, &mut entities);
+            entities.extend(self.outside
+                .iter()
+                .filter(|entity| aabb.contains(self.positions[entity])));
+
+            entities.sort_by_key(Entity::id);
+            entities
+
+        } // fn lookup()
+
+
+        fn lookup_range(&self, _: Bound<&dyn Any>, _: Bound<&dyn Any>) -> Option<Vec<Entity>> { None }
+
+    } // impl IndexStorage ..
+
+
+    impl QuadNode {
+        fn new(bounds: Aabb) -> Self {
+            QuadNode {
+                bounds,
+                entities: Vec::new(),
+                children: None,
+            } // QuadNode
+        } // fn new()
+
+
+        fn insert(
+            &mut self,
+            entity:   Entity,
+            position: [f32; 2],
+            capacity: usize,
+            depth:    usize,
+        ) {
+
+            if let Some(children) = &mut self.children {
+                return children[self.bounds.quadrant(position)].insert(entity, position, capacity, depth + 1usize)
+            } // if ..
+
+            self.entities.push((entity, position));
+            if self.entities.len() > capacity && depth < MAX_QUAD_DEPTH {
+
+                let [a, b, c, d] = self.bounds.quadrants();
+                self.children    = Some(Box::new([QuadNode::new(a), QuadNode::new(b), QuadNode::new(c), QuadNode::new(d)]));
+
+                std::mem::take(&mut self.entities)
+                    .into_iter()
+                    .for_each(|(entity, position)| self.insert(entity, position, capacity, depth));
+
+            } // if ..
+        } // fn insert()
+
+
+        fn remove(
+            &mut self,
+            entity:   Entity,
+            position: [f32; 2],
+            capacity: usize,
+        ) {
+
+            let Some(children) = &mut self.children else { return self.entities.retain(|(indexed, _)| *indexed != entity) };
+            children[self.bounds.quadrant(position)].remove(entity, position, capacity);
+
+            if children.iter().all(|child| child.children.is_none())
+                && children.iter().map(|child| child.entities.len()).sum::<usize>() <= capacity {
+                self.entities = children
+                    .iter_mut()
+                    .flat_map(|child| std::mem::take(&mut child.entities))
+                    .collect();
+                self.children = None;
+            } // if ..
+
+        } // fn remove()
+
+
+        fn query(&self, aabb: &Aabb, entities: &mut Vec<Entity>) {
+
+            if !self.bounds.intersects(aabb) { return }
+
+            entities.extend(self.entities
+                .iter()
+                .filter(|(_, position)| aabb.contains(*position))
+                .map(|(entity, _)| *entity));
+
+            if let Some(children) = &self.children {
+                children
+                    .iter()
+                    .for_each(|child| child.query(aabb, entities));
+            } // if ..
+
+        } // fn query()
+    } // impl QuadNode
+
+
+    pub(crate) fn read_position<S: SpatialIndex>(column: &dyn ComponentColumn, entity: Entity) -> Option<[f32; 2]> {
+        column
+            .as_any()
+            .downcast_ref::<HashMap<Entity, Rc<RefCell<S::Component>>>>()
+            .expect("Failed to downcast a component column!")
+            .get(&entity)
+            .map(|component| S::position(&component.borrow()))
+    } // fn read_position()
@@ -0,0 +1,128 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::collections::{HashMap, BTreeMap};
+    use std::hash::Hash;
+    use std::fmt::Debug;
+
+    use crate::worlds::{World, WorldBuilder};
+    use crate::entities::Entity;
+
+    use rusty_toolkit::BitField;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    pub struct Universe<B: BitField, F: BitField, P: Hash + Eq + Debug> {
+        registry:      WorldRegistry<B, F, P>,
+        worlds:        BTreeMap<WorldId, World<B, F, P>>,
+        next_world_id: usize,
+    } // struct Universe
+
+
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+    pub struct WorldId(usize);
+
+
+    type WorldRegistry<B, F, P> = Box<dyn Fn(WorldBuilder<B, F, P>) -> WorldBuilder<B, F, P>>;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<B: BitField, F: BitField, P: Hash + Eq + Debug> Universe<B, F, P> {
+        pub fn new(registry: impl Fn(WorldBuilder<B, F, P>) -> WorldBuilder<B, F, P> + 'static) -> Self {
+            Universe {
+                registry:      Box::new(registry),
+                worlds:        BTreeMap::default(),
+                next_world_id: 0usize,
+            } // Universe
+        } // fn new()
+
+
+        pub fn new_world(&mut self) -> WorldId {
+
+            let id = WorldId(self.next_world_id);
+            self.next_world_id += 1;
+            self.worlds.insert(id, (self.registry)(World::builder()).build());
+
+            id
+
+        } // fn new_world()
+
+
+        pub fn get_world(&self, id: WorldId) -> Option<&World<B, F, P>> { self.worlds.get(&id) }
+        pub fn get_world_mut(&mut self, id: WorldId) -> Option<&mut World<B, F, P>> { self.worlds.get_mut(&id) }
+        pub fn remove_world(&mut self, id: WorldId) -> Option<World<B, F, P>> { self.worlds.remove(&id) }
+        pub fn get_world_ids(&self) -> Vec<WorldId> { self.worlds.keys().copied().collect() }
+
+
+        pub fn move_entity(
+            &mut self,
+            from:   WorldId,
+            to:     WorldId,
+            entity: Entity,
+        ) -> Entity {
+
+            let source = self.worlds
+                .get(&from)
+                .expect("Attempted to find a world that was not registered!");
+
+            self.worlds
+                .get(&to)
+                .expect("Attempted to find a world that was not registered!")
+                .check_unique_components(source.get_entity_bit_mask(entity))
+                .unwrap_or_else(|error| panic!("{}", error));
+
+            let (bit_mask, components) = self.worlds
+                .get_mut(&from)
+                .expect("Attempted to find a world that was not registered!")
+                .take_entity(entity);
+
+            self.worlds
+                .get_mut(&to)
+                .expect("Attempted to find a world that was not registered!")
+                .insert_entity(bit_mask, components)
+
+        } // fn move_entity()
+
+
+        pub fn merge_worlds(&mut self, from: WorldId, into: WorldId) -> HashMap<Entity, Entity> {
+
+            assert!(from != into, "Attempted to merge a world into itself!");
+
+            let source = self.worlds
+                .get(&from)
+                .expect("Attempted to find a world that was not registered!");
+
+            let target = self.worlds
+                .get(&into)
+                .expect("Attempted to find a world that was not registered!");
+
+            source
+                .get_entity_ids()
+                .into_iter()
+                .try_for_each(|entity| target.check_unique_components(source.get_entity_bit_mask(entity)))
+                .unwrap_or_else(|error| panic!("{}", error));
+
+            let mut source = self.worlds
+                .remove(&from)
+                .expect("Attempted to find a world that was not registered!");
+            let target     = self.worlds
+                .get_mut(&into)
+                .expect("Attempted to find a world that was not registered!");
+
+            source
+                .get_entity_ids()
+                .into_iter()
+                .map(|entity| {
+                    let (bit_mask, components) = source.take_entity(entity);
+                    (entity, target.insert_entity(bit_mask, components))
+                }).collect()
+
+        } // fn merge_worlds()
+    } // impl Universe
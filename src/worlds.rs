@@ -2,17 +2,33 @@
 // D E P E N D E N C I E S
 //#########################
 
-    use std::collections::HashMap;
-    use std::any::TypeId;
+    use std::collections::{HashMap, HashSet, BTreeMap, BTreeSet};
+    use std::any::{Any, TypeId};
     use std::rc::Rc;
-    use std::cell::RefCell;
-    use std::ops::Range;
-    use std::hash::Hash;
-    use std::fmt::Debug;
-
-    use crate::components::{Component, ComponentCell, ComponentColumn};
+    use std::cell::{Ref, RefCell, RefMut};
+    use std::ops::{Bound, Range, RangeBounds};
+    use std::hash::{Hash, Hasher};
+    use std::fmt::{self, Debug, Display};
+    use std::error::Error;
+    use std::mem::size_of;
+
+    use crate::components::{Component, ComponentCell, ComponentColumn, ComponentId, ComponentInfo, BoxedComponents, Ptr, Shared, SharedCloning, unwrap_component_cell};
+    use crate::bundles::Bundle;
+    use crate::flags::{Flag, FlagType, flag_index_bit_mask, read_flag_index, read_flag_variant};
+    use crate::reflection::{Reflect, ReflectedComponent};
+    use crate::dynamics::{ComponentLayout, DynamicColumn, DynamicComponent, DynamicError};
+    use crate::inspectors::{MaskDescription, EntityInspection, WorldStats, ColumnStats};
     use crate::entities::{Entity, EntityBuilder, EntityId};
     use crate::queries::QueryBuilder;
+    #[cfg(feature = "spatial")]
+    use crate::spatial::{SpatialIndex, Aabb, GridIndex, QuadTreeIndex, read_position};
+    use crate::requirements::Requirement;
+    use crate::names::{Name, NameIndex, NamePolicy};
+    use crate::checksums::{StableHasher, checksum_component};
+    use crate::rollbacks::{WorldSnapshot, ColumnSnapshot, snapshot_column};
+    use crate::deltas::{WorldDelta, EntityMap, ReplicationInfo, ColumnChanges, DecodedComponents};
+    use crate::replication::{Replicate, ReplicationRule};
+    use crate::indexes::{ComponentIndex, IndexStorage, HashIndex, OrderedIndex, read_key};
 
     use rusty_toolkit::BitField;
 
@@ -22,36 +38,103 @@
 //#######################
 
     pub struct World<B: BitField, F: BitField, P: Hash + Eq + Debug> {
-        components:         Vec<TypeId>,
+        components:         Vec<ComponentInfo>,
         flags:              HashMap<F, Range<u8>>,
+        flag_types:         HashMap<TypeId, FlagType>,
         component_columns:  HashMap<B, Box<dyn ComponentColumn>>,
         component_pointers: HashMap<P, Box<dyn ComponentCell>>,
-        entities:           HashMap<Entity, B>,
+        requirements:       HashMap<usize, Vec<Requirement<B, F, P>>>,
+        names:              Option<NameIndex>,
+        indexes:            Vec<(usize, TypeId, Box<dyn IndexStorage>)>,
+        dirty_indexes:      RefCell<BTreeMap<usize, BTreeSet<Entity>>>,
+        entities:           BTreeMap<Entity, B>,
         next_entity_id:     EntityId,
     } // struct World
 
 
     pub struct WorldBuilder<B: BitField, F: BitField, P: Hash + Eq + Debug> {
-        components:         Vec<TypeId>,
+        components:         Vec<ComponentInfo>,
         flags:              HashMap<F, Range<u8>>,
+        flag_types:         Vec<(TypeId, FlagType)>,
         component_count:    usize,
         component_columns:  HashMap<B, Box<dyn ComponentColumn>>,
         component_pointers: HashMap<P, Box<dyn ComponentCell>>,
+        requirements:       HashMap<usize, Vec<Requirement<B, F, P>>>,
+        names:              Option<NameIndex>,
+        indexes:            Vec<(usize, TypeId, Box<dyn IndexStorage>)>,
     } // struct WorldBuilder
+
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum WorldError {
+        UniqueViolation {
+            component: String,
+            holder:    Entity,
+        }, // UniqueViolation
+        NoMatch(String),
+        MultipleMatches {
+            component: String,
+            count:     usize,
+        }, // MultipleMatches
+        RequiredComponent {
+            component: String,
+            dependent: String,
+        }, // RequiredComponent
+        InvalidComponent {
+            entity:    Entity,
+            component: String,
+            message:   String,
+        }, // InvalidComponent
+        DuplicateName {
+            name:   String,
+            holder: Entity,
+        }, // DuplicateName
+        UnregisteredComponent(String),
+        IndexedComponent(String),
+        SharedComponent(String),
+        UnknownEntity(Entity),
+        MalformedDelta(String),
+    } // enum WorldError
     
 
 //###############################
 // I M P L E M E N T A T I O N S
 //###############################
 
+    impl Display for WorldError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                WorldError::UniqueViolation { component, holder }           => write!(f, "The unique component {} is already held by the entity no.{}!", component, holder.id()),
+                WorldError::NoMatch(component)                              => write!(f, "No entity holds the component {}!", component),
+                WorldError::MultipleMatches { component, count }            => write!(f, "{} entities hold the component {} where one was expected!", count, component),
+                WorldError::RequiredComponent { component, dependent }      => write!(f, "The component {} cannot be removed as it is required by {}!", component, dependent),
+                WorldError::InvalidComponent { entity, component, message } => write!(f, "The component {} of the entity no.{} is invalid: {}", component, entity.id(), message),
+                WorldError::DuplicateName { name, holder }                  => write!(f, "The name {} is already held by the entity no.{}!", name, holder.id()),
+                WorldError::UnregisteredComponent(component)                => write!(f, "The component {} is not registered in the world!", component),
+                WorldError::IndexedComponent(component)                     => write!(f, "The component {} is indexed and must be modified through World::modify_component!", component),
+                WorldError::SharedComponent(component)                      => write!(f, "The component {} is shared and cannot be moved out without cloning!", component),
+                WorldError::UnknownEntity(entity)                           => write!(f, "The entity no.{} was never replicated!", entity.id()),
+                WorldError::MalformedDelta(message)                         => write!(f, "The delta is malformed: {}", message),
+            } // match ..
+        } // fn fmt()
+    } // impl Display ..
+
+
+    impl Error for WorldError {}
+
+
     impl<B: BitField, F: BitField, P: Hash + Eq + Debug> World<B, F, P> {
         pub fn builder() -> WorldBuilder<B, F, P> {
             WorldBuilder {
                 components:         Vec::default(),
                 flags:              HashMap::default(),
+                flag_types:         Vec::default(),
                 component_count:    0usize,
                 component_columns:  HashMap::default(),
                 component_pointers: HashMap::default(),
+                requirements:       HashMap::default(),
+                names:              None,
+                indexes:            Vec::default(),
             } // WorldBuilder
         } // fn builder()
 
@@ -61,8 +144,8 @@
             self.components
                 .iter()
                 .enumerate()
-                .find_map(|(index, id)| {
-                    return match id == &TypeId::of::<C>() {
+                .find_map(|(index, info)| {
+                    return match info.type_id == Some(TypeId::of::<C>()) {
                         true  => Some(B::bit(index as u8)),
                         false => None,
                     } // return ..
@@ -72,18 +155,54 @@
 
 
         pub(crate) fn flag_bit_mask(&self, flag: F, variant: Option<B>) -> B {
-            self.flags
-                .iter()
-                .find_map(|(id, range)| {
-                    return match id == &flag {
-                        true => Some(match variant {
-                            Some(variant) => ((variant << range.start) & B::bit_mask(range.clone())) << self.components.len() as u8,
-                            None          => B::bit_mask(range.clone()),
-                        }), // => ..
-                        false => None,
-                    } // return ..
-                }).expect("Attempted to get a flag bit mask that was not registered!")
-        } // fn component_bit_mask()
+
+            let range = self.flags
+                .get(&flag)
+                .expect("Attempted to get a flag bit mask that was not registered!");
+
+            match variant {
+                Some(variant) => {
+
+                    assert!(
+                        (variant << range.start) & !B::bit_mask(range.clone()) == B::MIN,
+                        "Attempted to use a flag variant that overflows its range!"
+                    );
+
+                    (variant << range.start) << self.flag_offset()
+
+                }, // => ..
+                None => B::bit_mask(self.flag_range(flag)),
+            } // match ..
+        } // fn flag_bit_mask()
+
+
+        // component bits come first, flag ranges are laid out right after them
+        pub(crate) fn flag_offset(&self) -> u8 { self.components.len() as u8 }
+
+
+        pub(crate) fn flag_range(&self, flag: F) -> Range<u8> {
+
+            let range  = self.flags
+                .get(&flag)
+                .expect("Attempted to get a flag range that was not registered!");
+            let offset = self.flag_offset();
+
+            range.start + offset..range.end + offset
+
+        } // fn flag_range()
+
+
+        pub(crate) fn flag_type_range<T: Flag>(&self) -> Range<u8> {
+
+            let range  = &self.flag_types
+                .get(&TypeId::of::<T>())
+                .expect("Attempted to get a flag range that was not registered!")
+                .range;
+            let offset = self.flag_offset();
+
+            range.start + offset..range.end + offset
+
+        } // fn flag_type_range()
 
 
         pub(crate) fn add_component_to_entity_builder<C: Component>(
@@ -93,16 +212,8 @@
             entity_bit_mask: &mut B,
         ) {
 
-            let bit_mask     = self.component_bit_mask::<C>();
-            *entity_bit_mask |= bit_mask;
-
-            self.component_columns
-                .get_mut(&bit_mask)
-                .expect("Attempted to find a component column that was not registered!")
-                .as_any_mut()
-                .downcast_mut::<HashMap<Entity, Rc<RefCell<C>>>>()
-                .expect("Failed to downcast a component column!")
-                .insert(entity, Rc::new(RefCell::new(component)));
+            *entity_bit_mask |= self.component_bit_mask::<C>();
+            self.insert_component_cell(entity, Rc::new(RefCell::new(component)));
 
         } // fn add_component_to_entity_builder()
 
@@ -114,20 +225,25 @@
             entity_bit_mask: &mut B,
         ) {
 
-            let bit_mask     = self.component_bit_mask::<C>();
-            *entity_bit_mask |= bit_mask;
-
-            self.component_columns
-                .get_mut(&bit_mask)
-                .expect("Attempted to find a component column that was not registered!")
-                .as_any_mut()
-                .downcast_mut::<HashMap<Entity, Rc<RefCell<C>>>>()
-                .expect("Failed to downcast a component column!")
-                .insert(entity, component.clone());
+            *entity_bit_mask |= self.component_bit_mask::<C>();
+            self.insert_component_cell(entity, component.clone());
         
         } // fn add_shared_component_to_entity_builder()
 
 
+        pub(crate) fn add_bundle_to_entity_builder<T: Bundle>(
+            &mut self,
+            bundle:          T,
+            entity:          Entity,
+            entity_bit_mask: &mut B,
+        ) {
+
+            *entity_bit_mask |= T::bit_mask(self);
+            bundle.insert_components(self, entity);
+
+        } // fn add_bundle_to_entity_builder()
+
+
         pub(crate) fn add_flag_to_entity_builder(
             &mut self,
             flag:            F,
@@ -136,11 +252,29 @@
         ) {
 
             let bit_mask     = self.flag_bit_mask(flag, variant);
+            *entity_bit_mask &= !B::bit_mask(self.flag_range(flag));
             *entity_bit_mask |= bit_mask;
 
         } // fn add_flag_to_entity_builder()
 
 
+        pub(crate) fn add_typed_flag_to_entity_builder<T: Flag>(
+            &self,
+            flag:            T,
+            entity_bit_mask: &mut B,
+        ) {
+
+            let range        = self.flag_type_range::<T>();
+            *entity_bit_mask &= !B::bit_mask(range.clone());
+            *entity_bit_mask |= flag_index_bit_mask(range, flag.index());
+
+        } // fn add_typed_flag_to_entity_builder()
+
+
+        pub fn contains_entity(&self, entity: Entity) -> bool { self.entities.contains_key(&entity) }
+        pub fn component_count(&self) -> usize { self.components.len() }
+
+
         pub fn entity_has_component<C: Component>(&self, entity: Entity) -> bool {
 
             let bit_mask = self.component_bit_mask::<C>();
@@ -177,6 +311,22 @@
         } // fn entity_has_flag()
 
 
+        pub fn get_entity_flag_variant(&self, entity: Entity, flag: F) -> Option<B> {
+
+            let variant = read_flag_variant(
+                *self.entities
+                    .get(&entity)
+                    .expect("Attempted to find an entity that was not registered!"),
+                self.flag_range(flag),
+            ); // let variant
+
+            match variant == B::MIN {
+                true  => None,
+                false => Some(variant),
+            } // match ..
+        } // fn get_entity_flag_variant()
+
+
         pub fn entity_group_has_flag(
             &self,
             entity_group: &[Entity],
@@ -200,21 +350,31 @@
             component: C,
             entity:    Entity,
         ) {
+            self.try_add_component_to_entity(component, entity)
+                .unwrap_or_else(|error| panic!("{}", error));
+        } // fn add_component_to_entity()
+
+
+        pub fn try_add_component_to_entity<C: Component>(
+            &mut self,
+            component: C,
+            entity:    Entity,
+        ) -> Result<(), WorldError> {
+
+            let index = self.component_id::<C>().index() as usize;
+            self.check_unique_component(index, &[entity])?;
+            self.validate_value(index, entity, &component)?;
 
             let bit_mask = self.component_bit_mask::<C>();
             *self.entities
                 .get_mut(&entity)
                 .expect("Attempted to find an entity that was not registered!") |= bit_mask;
 
-            self.component_columns
-                .get_mut(&bit_mask)
-                .expect("Attempted to find a component column that was not registered!")
-                .as_any_mut()
-                .downcast_mut::<HashMap<Entity, Rc<RefCell<C>>>>()
-                .expect("Failed to downcast a component column!")
-                .insert(entity, Rc::new(RefCell::new(component)));
+            self.insert_component_cell(entity, Rc::new(RefCell::new(component)));
+            self.fulfil_requirements(entity);
+            Ok(())
 
-        } // fn add_component_to_entity()
+        } // fn try_add_component_to_entity()
 
 
         pub fn add_component_to_entity_group<C: Component>(
@@ -223,6 +383,14 @@
             entity_group: &[Entity],
         ) {
 
+            let index = self.component_id::<C>().index() as usize;
+            self.check_unique_component(index, entity_group)
+                .and_then(|_| entity_group
+                    .iter()
+                    .try_for_each(|entity| self.validate_value(index, *entity, &component)))
+                .and_then(|_| self.check_name_available(entity_group, &component))
+                .unwrap_or_else(|error| panic!("{}", error));
+
             let bit_mask         = self.component_bit_mask::<C>();
             let component_column = self.component_columns
                 .get_mut(&bit_mask)
@@ -240,6 +408,14 @@
                         .expect("Attempted to find an entity that was not registered!") |= bit_mask;
 
                 }); // for_each()
+
+            entity_group
+                .iter()
+                .for_each(|entity| {
+                    self.update_component_indexes(index, *entity);
+                    self.fulfil_requirements(*entity);
+                }); // for_each()
+
         } // fn add_component_to_entity_group()
 
 
@@ -249,28 +425,93 @@
             entity:    Entity,
         ) {
 
+            self.validate_value(self.component_id::<C>().index() as usize, entity, &*component.borrow())
+                .unwrap_or_else(|error| panic!("{}", error));
+
             let bit_mask = self.component_bit_mask::<C>();
             *self.entities
                 .get_mut(&entity)
                 .expect("Attempted to find an entity that was not registered!") |= bit_mask;
 
-            self.component_columns
-                .get_mut(&bit_mask)
-                .expect("Attempted to find a component column that was not registered!")
-                .as_any_mut()
-                .downcast_mut::<HashMap<Entity, Rc<RefCell<C>>>>()
-                .expect("Failed to downcast a component column!")
-                .insert(entity, component.clone());
+            self.insert_component_cell(entity, component.clone());
+            self.fulfil_requirements(entity);
 
         } // fn add_shared_component_to_entity()
 
 
+        pub fn is_component_shared<C: Component>(&self, entity: Entity) -> bool { self.get_sharer_count::<C>(entity) > 1usize }
+
+
+        pub fn get_sharer_count<C: Component>(&self, entity: Entity) -> usize {
+
+            let Some(component) = self.get_component_column::<C>().get(&entity) else { return 0usize };
+            self.get_component_column::<C>()
+                .values()
+                .filter(|sharer| Rc::ptr_eq(component, sharer))
+                .count()
+
+        } // fn get_sharer_count()
+
+
+        pub fn make_component_unique<C: Component>(&mut self, entity: Entity) -> bool {
+
+            if !self.is_component_shared::<C>(entity) { return false }
+
+            let component = self.get_component_column_mut::<C>()
+                .get_mut(&entity)
+                .expect("Attempted to make unique a component that was not registered!");
+
+            let unique = component.borrow().clone();
+            *component = Rc::new(RefCell::new(unique));
+            true
+
+        } // fn make_component_unique()
+
+
+        pub fn insert_bundle<T: Bundle>(
+            &mut self,
+            entity: Entity,
+            bundle: T,
+        ) {
+            self.try_insert_bundle(entity, bundle)
+                .unwrap_or_else(|error| panic!("{}", error));
+        } // fn insert_bundle()
+
+
+        pub fn try_insert_bundle<T: Bundle>(
+            &mut self,
+            entity: Entity,
+            bundle: T,
+        ) -> Result<(), WorldError> {
+
+            bundle.validate_components(self, entity)?;
+
+            let bit_mask = T::bit_mask(self);
+            *self.entities
+                .get_mut(&entity)
+                .expect("Attempted to find an entity that was not registered!") |= bit_mask;
+
+            bundle.insert_components(self, entity);
+            self.fulfil_requirements(entity);
+            Ok(())
+
+        } // fn try_insert_bundle()
+
+
         pub fn add_shared_component_to_entity_group<C: Component>(
             &mut self,
             component:    &Rc<RefCell<C>>,
             entity_group: &[Entity],
         ) {
 
+            let index = self.component_id::<C>().index() as usize;
+            self.check_unique_component(index, entity_group)
+                .and_then(|_| entity_group
+                    .iter()
+                    .try_for_each(|entity| self.validate_value(index, *entity, &*component.borrow())))
+                .and_then(|_| self.check_name_available(entity_group, &*component.borrow()))
+                .unwrap_or_else(|error| panic!("{}", error));
+
             let bit_mask         = self.component_bit_mask::<C>();
             let component_column = self.component_columns
                 .get_mut(&bit_mask)
@@ -288,17 +529,23 @@
                         .expect("Attempted to find an entity that was not registered!") |= bit_mask;
 
                 }); // for_each()
+
+            entity_group
+                .iter()
+                .for_each(|entity| {
+                    self.update_component_indexes(index, *entity);
+                    self.fulfil_requirements(*entity);
+                }); // for_each()
+
         } // fn add_component_to_entity_group()
 
 
         pub fn get_entity_component<C: Component>(&self, entity: Entity) -> Option<&Rc<RefCell<C>>> {
-            self.component_columns
-                .get(&self.component_bit_mask::<C>())
-                .expect("Attempted to find a component column that was not registered!")
-                .as_any()
-                .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()
-                .expect("Failed to downcast a component column!")
-                .get(&entity)
+
+            let component = self.get_component_column::<C>().get(&entity)?;
+            self.mark_indexes_dirty(self.component_id::<C>().index() as usize, &[entity]);
+            Some(component)
+
         } // fn get_entity_component()
 
 
@@ -311,6 +558,7 @@
                 .downcast_ref::<HashMap<Entity, Rc<RefCell<C>>>>()
                 .expect("Failed to downcast a component column!");
 
+            self.mark_indexes_dirty(self.component_id::<C>().index() as usize, entity_group);
             entity_group
                 .iter()
                 .map(|entity| component_column.get(entity))
@@ -320,8 +568,15 @@
 
 
         pub fn delete_entity_component<C: Component>(&mut self, entity: Entity) {
+            self.try_delete_entity_component::<C>(entity)
+                .unwrap_or_else(|error| panic!("{}", error));
+        } // fn delete_entity_component()
+
+
+        pub fn try_delete_entity_component<C: Component>(&mut self, entity: Entity) -> Result<(), WorldError> {
 
             let bit_mask = self.component_bit_mask::<C>();
+            self.check_required_removal(entity, bit_mask)?;
 
             *self.entities
                 .get_mut(&entity)
@@ -332,12 +587,20 @@
                 .unwrap()
                 .remove_entity(entity);
 
-        } // fn delete_entity_component()
+            self.update_component_indexes(self.component_id::<C>().index() as usize, entity);
+            Ok(())
+
+        } // fn try_delete_entity_component()
 
 
         pub fn delete_entity_group_component<C: Component>(&mut self, entity_group: &[Entity]) {
 
             let bit_mask         = self.component_bit_mask::<C>();
+            entity_group
+                .iter()
+                .try_for_each(|entity| self.check_required_removal(*entity, bit_mask))
+                .unwrap_or_else(|error| panic!("{}", error));
+
             let component_column = self.component_columns
                 .get_mut(&bit_mask)
                 .expect("Attempted to find a component column that was not registered!")
@@ -355,9 +618,111 @@
                         .expect("Attempted to find an entity that was not registered!") &= !bit_mask;
 
                 }); // for_each()
+
+            let index = self.component_id::<C>().index() as usize;
+            entity_group
+                .iter()
+                .for_each(|entity| self.update_component_indexes(index, *entity));
+
         } // fn delete_entity_group_component()
 
 
+        pub fn take_component<C: Component>(&mut self, entity: Entity) -> Option<C> {
+            self.try_take_component::<C>(entity)
+                .unwrap_or_else(|error| panic!("{}", error))
+        } // fn take_component()
+
+
+        pub fn try_take_component<C: Component>(&mut self, entity: Entity) -> Result<Option<C>, WorldError> {
+
+            self.check_component_unshared::<C>(entity)?;
+            Ok(self.try_take_shared_component::<C>(entity)?
+                .and_then(|component| component.into_inner().ok()))
+
+        } // fn try_take_component()
+
+
+        pub fn take_shared_component<C: Component>(&mut self, entity: Entity) -> Option<Shared<C>> {
+            self.try_take_shared_component::<C>(entity)
+                .unwrap_or_else(|error| panic!("{}", error))
+        } // fn take_shared_component()
+
+
+        pub fn try_take_shared_component<C: Component>(&mut self, entity: Entity) -> Result<Option<Shared<C>>, WorldError> {
+
+            let bit_mask = self.component_bit_mask::<C>();
+            self.check_required_removal(entity, bit_mask)?;
+
+            *self.entities
+                .get_mut(&entity)
+                .expect("Attempted to find an entity that was not registered!") &= !bit_mask;
+
+            Ok(self.remove_component_cell::<C>(entity)
+                .map(Shared::from_cell))
+
+        } // fn try_take_shared_component()
+
+
+        fn check_component_unshared<C: Component>(&self, entity: Entity) -> Result<(), WorldError> {
+            match self.get_component_column::<C>().get(&entity).is_some_and(|component| Rc::strong_count(component) > 1usize) {
+                true  => Err(WorldError::SharedComponent(self.components[self.component_id::<C>().index() as usize].name.clone())),
+                false => Ok(()),
+            } // match ..
+        } // fn check_component_unshared()
+
+
+        pub fn replace_component<C: Component>(
+            &mut self,
+            entity:    Entity,
+            component: C,
+        ) -> Option<C> {
+            self.try_replace_component(entity, component)
+                .unwrap_or_else(|error| panic!("{}", error))
+        } // fn replace_component()
+
+
+        pub fn try_replace_component<C: Component>(
+            &mut self,
+            entity:    Entity,
+            component: C,
+        ) -> Result<Option<C>, WorldError> {
+
+            let index = self.component_id::<C>().index() as usize;
+            self.check_unique_component(index, &[entity])?;
+            self.validate_value(index, entity, &component)?;
+            self.check_component_unshared::<C>(entity)?;
+
+            let bit_mask = self.component_bit_mask::<C>();
+            *self.entities
+                .get_mut(&entity)
+                .expect("Attempted to find an entity that was not registered!") |= bit_mask;
+
+            let replaced = self.get_component_column_mut::<C>()
+                .insert(entity, Rc::new(RefCell::new(component)))
+                .and_then(|component| unwrap_component_cell(component).ok());
+
+            self.update_component_indexes(index, entity);
+            self.fulfil_requirements(entity);
+            Ok(replaced)
+
+        } // fn try_replace_component()
+
+
+        pub fn remove_bundle<T: Bundle>(&mut self, entity: Entity) {
+
+            let bit_mask = T::bit_mask(self);
+            self.check_required_removal(entity, bit_mask)
+                .unwrap_or_else(|error| panic!("{}", error));
+
+            *self.entities
+                .get_mut(&entity)
+                .expect("Attempted to find an entity that was not registered!") &= !bit_mask;
+
+            T::remove_components(self, entity);
+
+        } // fn remove_bundle()
+
+
         pub fn set_entity_flag(
             &mut self,
             entity:  Entity,
@@ -365,10 +730,14 @@
             variant: Option<B>,
         ) {
 
-            let bit_mask = self.flag_bit_mask(flag, variant);
-            *self.entities
+            let bit_mask        = self.flag_bit_mask(flag, variant);
+            let range_bit_mask  = B::bit_mask(self.flag_range(flag));
+            let entity_bit_mask = self.entities
                 .get_mut(&entity)
-                .expect("Attempted to find an entity that was not registered!") |= bit_mask;
+                .expect("Attempted to find an entity that was not registered!");
+
+            *entity_bit_mask &= !range_bit_mask;
+            *entity_bit_mask |= bit_mask;
 
         } // fn set_entity_flag()
 
@@ -380,16 +749,50 @@
             variant:      Option<B>,
         ) {
 
-            let bit_mask = self.flag_bit_mask(flag, variant);
+            let bit_mask       = self.flag_bit_mask(flag, variant);
+            let range_bit_mask = B::bit_mask(self.flag_range(flag));
 
             entity_group
                 .iter()
-                .for_each(|entity| *self.entities
+                .for_each(|entity| {
+
+                    let entity_bit_mask = self.entities
                         .get_mut(entity)
-                        .expect("Attempted to find an entity that was not registered!") |= bit_mask);
+                        .expect("Attempted to find an entity that was not registered!");
+
+                    *entity_bit_mask &= !range_bit_mask;
+                    *entity_bit_mask |= bit_mask;
+
+                }); // for_each()
         } // fn set_entity_group_flag()
 
 
+        pub fn set_flag<T: Flag>(&mut self, entity: Entity, flag: T) {
+
+            let range           = self.flag_type_range::<T>();
+            let entity_bit_mask = self.entities
+                .get_mut(&entity)
+                .expect("Attempted to find an entity that was not registered!");
+
+            *entity_bit_mask &= !B::bit_mask(range.clone());
+            *entity_bit_mask |= flag_index_bit_mask(range, flag.index());
+
+        } // fn set_flag()
+
+
+        pub fn get_flag<T: Flag>(&self, entity: Entity) -> T {
+
+            let range           = self.flag_type_range::<T>();
+            let entity_bit_mask = self.entities
+                .get(&entity)
+                .expect("Attempted to find an entity that was not registered!");
+
+            T::from_index(read_flag_index(*entity_bit_mask, range))
+                .expect("Attempted to read a flag variant that was not listed!")
+
+        } // fn get_flag()
+
+
         pub fn remove_entity_flag(
             &mut self,
             entity:  Entity,
@@ -439,32 +842,604 @@
         } // fn get_component_column()
 
 
-        pub fn get_pointer_component<C: Component>(&self, id: P) -> &Rc<RefCell<C>> {
-            self.component_pointers
-                .get(&id)
-                .expect("Attempted to find a component pointer that was not registered!")
-                .as_any()
-                .downcast_ref::<Rc<RefCell<C>>>()
-                .expect("Failed to downcast a component!")
-        } // fn get_pointer_component()
+        pub(crate) fn get_component_column_mut<C: Component>(&mut self) -> &mut HashMap<Entity, Rc<RefCell<C>>> {
+            self.component_columns
+                .get_mut(&self.component_bit_mask::<C>())
+                .expect("Attempted to find a component column that was not registered!")
+                .as_any_mut()
+                .downcast_mut::<HashMap<Entity, Rc<RefCell<C>>>>()
+                .expect("Failed to downcast a component column!")
+        } // fn get_component_column_mut()
 
 
-        pub(crate) fn get_entities(&self, bit_mask_filter: B) -> Vec<Entity> {
-            self.entities
-                .iter()
-                .filter(|(_, bit_mask)| **bit_mask & bit_mask_filter == bit_mask_filter)
-                .map(|(entity, _)| *entity)
-                .collect()
-        } // fn get_entities()
+        pub(crate) fn insert_component_cell<C: Component>(
+            &mut self,
+            entity:    Entity,
+            component: Rc<RefCell<C>>,
+        ) {
 
+            let index = self.component_id::<C>().index() as usize;
+            self.check_unique_component(index, &[entity])
+                .unwrap_or_else(|error| panic!("{}", error));
 
-        pub fn delete_entity(&mut self, entity: Entity) {
+            self.get_component_column_mut::<C>().insert(entity, component);
+            self.update_component_indexes(index, entity);
 
-            let entity_mask = self.entities
-                .get(&entity)
-                .expect("Attempted to delete an entity that was not registered!");
+        } // fn insert_component_cell()
 
-            self.component_columns
+
+        pub(crate) fn check_unique_component(&self, index: usize, entity_group: &[Entity]) -> Result<(), WorldError> {
+
+            let info = &self.components[index];
+            if !info.unique { return Ok(()) }
+
+            let column = self.component_columns
+                .get(&B::bit(index as u8))
+                .expect("Attempted to find a component column that was not registered!");
+
+            let holder = column
+                .get_entities()
+                .into_iter()
+                .find(|holder| !entity_group.contains(holder))
+                .or_else(|| entity_group
+                    .iter()
+                    .copied()
+                    .find(|holder| *holder != entity_group[0]));
+
+            match holder {
+                Some(holder) => Err(WorldError::UniqueViolation {
+                    component: info.name.clone(),
+                    holder,
+                }), // => ..
+                None => Ok(()),
+            } // match ..
+
+        } // fn check_unique_component()
+
+
+        pub(crate) fn check_unique_components(&self, bit_mask: B) -> Result<(), WorldError> {
+            (0..self.components.len())
+                .filter(|index| bit_mask.has_bits(B::bit(*index as u8)))
+                .try_for_each(|index| self.check_unique_component(index, &[]))
+        } // fn check_unique_components()
+
+
+        pub(crate) fn insert_required_components(&mut self, entity: Entity, bit_mask: B) -> B {
+
+            let mut bit_mask = bit_mask;
+            let mut pending  = self.get_dependent_indices(bit_mask);
+
+            while let Some(index) = pending.pop() {
+                self.requirements
+                    .get(&index)
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .for_each(|requirement| {
+
+                        let inserted = (requirement.insert)(self, entity, bit_mask);
+                        bit_mask    |= inserted;
+                        pending.extend(self.get_dependent_indices(inserted));
+
+                    }); // for_each()
+            } // while ..
+
+            bit_mask
+
+        } // fn insert_required_components()
+
+
+        pub(crate) fn fulfil_requirements(&mut self, entity: Entity) {
+
+            if self.requirements.is_empty() { return }
+
+            let bit_mask = self.get_entity_bit_mask(entity);
+            let bit_mask = self.insert_required_components(entity, bit_mask);
+            self.entities.insert(entity, bit_mask);
+
+        } // fn fulfil_requirements()
+
+
+        pub(crate) fn check_required_removal(&self, entity: Entity, removed: B) -> Result<(), WorldError> {
+
+            let remaining = self.get_entity_bit_mask(entity) & !removed;
+            let violation = self.requirements
+                .iter()
+                .filter(|(index, _)| remaining.has_bits(B::bit(**index as u8)))
+                .find_map(|(index, requirements)| requirements
+                    .iter()
+                    .map(|requirement| (requirement.bit_mask)(self) & removed)
+                    .find(|missing| *missing != B::MIN)
+                    .map(|missing| (*index, missing)));
+
+            match violation {
+                Some((index, missing)) => Err(WorldError::RequiredComponent {
+                    component: self.components
+                        .iter()
+                        .enumerate()
+                        .find(|(required, _)| missing.has_bits(B::bit(*required as u8)))
+                        .map(|(_, info)| info.name.clone())
+                        .expect("Attempted to find a required component that was not registered!"),
+                    dependent: self.components[index].name.clone(),
+                }), // => ..
+                None => Ok(()),
+            } // match ..
+
+        } // fn check_required_removal()
+
+
+        pub(crate) fn validate_value(
+            &self,
+            index:     usize,
+            entity:    Entity,
+            component: &dyn Any,
+        ) -> Result<(), WorldError> {
+
+            let info = &self.components[index];
+            info.validators
+                .iter()
+                .try_for_each(|validator| validator(component))
+                .map_err(|message| WorldError::InvalidComponent {
+                    entity,
+                    component: info.name.clone(),
+                    message,
+                })?; // map_err()
+
+            self.check_name_available(&[entity], component)
+
+        } // fn validate_value()
+
+
+        #[doc(hidden)]
+        pub fn validate_component<C: Component>(&self, entity: Entity, component: &C) -> Result<(), WorldError> {
+            self.validate_value(self.component_id::<C>().index() as usize, entity, component)
+        } // fn validate_component()
+
+
+        pub(crate) fn validate_boxed(
+            &self,
+            index:     usize,
+            entity:    Entity,
+            component: &dyn Any,
+        ) -> Result<(), WorldError> {
+
+            let info   = &self.components[index];
+            let column = self.component_columns
+                .get(&B::bit(index as u8))
+                .expect("Attempted to find a component column that was not registered!");
+
+            info.validators
+                .iter()
+                .try_for_each(|validator| column.validate_component(component, validator))
+                .map_err(|message| WorldError::InvalidComponent {
+                    entity,
+                    component: info.name.clone(),
+                    message,
+                })?; // map_err()
+
+            match component.downcast_ref::<Rc<RefCell<Name>>>() {
+                Some(name) => self.check_name_available(&[entity], &*name.borrow()),
+                None       => Ok(()),
+            } // match ..
+
+        } // fn validate_boxed()
+
+
+        pub(crate) fn validate_components(&self, entity: Entity, bit_mask: B) -> Result<(), WorldError> {
+
+            self.components
+                .iter()
+                .enumerate()
+                .filter(|(index, info)| !info.validators.is_empty() && bit_mask.has_bits(B::bit(*index as u8)))
+                .try_for_each(|(index, info)| match self.get_by_id(entity, ComponentId(index as u8)) {
+                    Some(component) => info.validators
+                        .iter()
+                        .try_for_each(|validator| component.validate(validator))
+                        .map_err(|message| WorldError::InvalidComponent {
+                            entity,
+                            component: info.name.clone(),
+                            message,
+                        }), // => ..
+                    None => Ok(()),
+                })?; // try_for_each()
+
+            match self.get_entity_name(entity) {
+                Some(name) => self.check_name_available(&[entity], &*name),
+                None       => Ok(()),
+            } // match ..
+
+        } // fn validate_components()
+
+
+        pub fn validate(&self) -> Result<(), Vec<WorldError>> {
+
+            let errors = self.get_entity_ids()
+                .into_iter()
+                .filter_map(|entity| self.validate_components(entity, self.get_entity_bit_mask(entity)).err())
+                .collect::<Vec<WorldError>>();
+
+            match errors.is_empty() {
+                true  => Ok(()),
+                false => Err(errors),
+            } // match ..
+
+        } // fn validate()
+
+
+        pub fn debug_validate(&self) {
+            if cfg!(debug_assertions) {
+                if let Err(errors) = self.validate() {
+                    panic!("{}", errors
+                        .iter()
+                        .map(WorldError::to_string)
+                        .collect::<Vec<String>>()
+                        .join("\n"));
+                } // if ..
+            } // if ..
+        } // fn debug_validate()
+
+
+        pub(crate) fn discard_entity_components(&mut self, entity: Entity, bit_mask: B) {
+
+            self.component_columns
+                .iter_mut()
+                .filter(|(column_bit_mask, _)| bit_mask.has_bits(**column_bit_mask))
+                .for_each(|(_, component_column)| component_column.remove_entity(entity));
+
+            self.remove_indexed_entity(entity);
+
+        } // fn discard_entity_components()
+
+
+        pub fn find_by_name(&self, name: &str) -> Option<Entity> { self.find_all_by_name(name).into_iter().next() }
+
+
+        pub fn find_all_by_name(&self, name: &str) -> Vec<Entity> {
+
+            match &self.names {
+                Some(names) => self.get_index_candidates(self.component_id::<Name>().index() as usize, names.get(name).to_vec())
+                    .into_iter()
+                    .filter(|entity| self.get_entity_name(*entity).is_some_and(|named| named.as_str() == name))
+                    .collect(),
+                None => Vec::new(),
+            } // match ..
+
+        } // fn find_all_by_name()
+
+
+        pub fn get_entity_name(&self, entity: Entity) -> Option<Ref<'_, Name>> {
+            self.names.as_ref()?;
+            self.get_component_column::<Name>()
+                .get(&entity)
+                .map(|name| name.borrow())
+        } // fn get_entity_name()
+
+
+        pub fn rename_entity(&mut self, entity: Entity, name: impl Into<Name>) -> Result<(), WorldError> {
+            self.try_add_component_to_entity(name.into(), entity)
+        } // fn rename_entity()
+
+
+        fn check_name_available(&self, entity_group: &[Entity], component: &dyn Any) -> Result<(), WorldError> {
+
+            let (Some(names), Some(name)) = (&self.names, component.downcast_ref::<Name>()) else { return Ok(()) };
+            if names.policy == NamePolicy::AllowDuplicates { return Ok(()) }
+
+            let holder = self.find_all_by_name(name.as_str())
+                .into_iter()
+                .find(|holder| !entity_group.contains(holder))
+                .or_else(|| entity_group.get(1usize).copied());
+
+            match holder {
+                Some(holder) => Err(WorldError::DuplicateName {
+                    name: name.to_string(),
+                    holder,
+                }), // => ..
+                None => Ok(()),
+            } // match ..
+
+        } // fn check_name_available()
+
+
+        fn index_component_name(&mut self, index: usize, entity: Entity) {
+
+            if self.names.is_none() || self.components[index].type_id != Some(TypeId::of::<Name>()) { return }
+
+            let name = self.get_component_column::<Name>().get(&entity).map(|name| name.borrow().clone());
+            if let Some(names) = &mut self.names {
+                match name {
+                    Some(name) => names.insert(name.as_str(), entity),
+                    None       => names.remove_entity(entity),
+                } // match ..
+            } // if ..
+
+        } // fn index_component_name()
+
+
+        pub(crate) fn update_component_indexes(&mut self, index: usize, entity: Entity) {
+
+            if let Some(dirty) = self.dirty_indexes.get_mut().get_mut(&index) { dirty.remove(&entity); }
+            self.index_component_name(index, entity);
+
+            let column = self.component_columns
+                .get(&B::bit(index as u8))
+                .expect("Attempted to find a component column that was not registered!");
+
+            self.indexes
+                .iter_mut()
+                .filter(|(component, _, _)| *component == index)
+                .for_each(|(_, _, storage)| storage.update(column.as_ref(), entity));
+
+        } // fn update_component_indexes()
+
+
+        fn remove_indexed_entity(&mut self, entity: Entity) {
+
+            if let Some(names) = &mut self.names { names.remove_entity(entity) }
+            self.dirty_indexes
+                .get_mut()
+                .values_mut()
+                .for_each(|dirty| { dirty.remove(&entity); });
+
+            self.indexes
+                .iter_mut()
+                .for_each(|(_, _, storage)| storage.remove_entity(entity));
+
+        } // fn remove_indexed_entity()
+
+
+        fn get_index_storage<I: 'static>(&self) -> (usize, &dyn IndexStorage) {
+            self.indexes
+                .iter()
+                .find(|(_, id, _)| *id == TypeId::of::<I>())
+                .map(|(component, _, storage)| (*component, storage.as_ref()))
+                .expect("Attempted to find an index that was not registered!")
+        } // fn get_index_storage()
+
+
+        pub fn lookup_index<I: ComponentIndex>(&self, key: &I::Key) -> Vec<Entity> {
+
+            let (component, storage) = self.get_index_storage::<I>();
+            let column               = self.component_columns
+                .get(&B::bit(component as u8))
+                .expect("Attempted to find a component column that was not registered!");
+
+            self.get_index_candidates(component, storage.lookup(key))
+                .into_iter()
+                .filter(|entity| read_key::<I>(column.as_ref(), *entity).as_ref() == Some(key))
+                .collect()
+
+        } // fn lookup_index()
+
+
+        pub fn lookup_index_range<I: ComponentIndex>(&self, range: impl RangeBounds<I::Key>) -> Vec<Entity> where I::Key: Ord {
+
+            let (component, storage) = self.get_index_storage::<I>();
+            let column               = self.component_columns
+                .get(&B::bit(component as u8))
+                .expect("Attempted to find a component column that was not registered!");
+
+            let indexed = storage
+                .lookup_range(erase_bound(range.start_bound()), erase_bound(range.end_bound()))
+                .expect("Attempted a range lookup on an index that is not ordered!");
+
+            let mut entities = self.get_index_candidates(component, indexed)
+                .into_iter()
+                .filter_map(|entity| read_key::<I>(column.as_ref(), entity)
+                    .filter(|key| range.contains(key))
+                    .map(|key| (key, entity)))
+                .collect::<Vec<(I::Key, Entity)>>();
+
+            entities.sort_by(|(a, _), (b, _)| a.cmp(b));
+            entities
+                .into_iter()
+                .map(|(_, entity)| entity)
+                .collect()
+
+        } // fn lookup_index_range()
+
+
+        #[cfg(feature = "spatial")]
+        pub fn query_aabb<S: SpatialIndex>(&self, aabb: Aabb) -> Vec<Entity> {
+
+            let (component, storage) = self.get_index_storage::<S>();
+            let column               = self.component_columns
+                .get(&B::bit(component as u8))
+                .expect("Attempted to find a component column that was not registered!");
+
+            self.get_index_candidates(component, storage.lookup(&aabb))
+                .into_iter()
+                .filter(|entity| read_position::<S>(column.as_ref(), *entity).is_some_and(|position| aabb.contains(position)))
+                .collect()
+
+        } // fn query_aabb()
+
+
+        #[cfg(feature = "spatial")]
+        pub fn query_radius<S: SpatialIndex>(&self, center: [f32; 2], radius: f32) -> Vec<Entity> {
+
+            let column = self.component_columns
+                .get(&B::bit(self.get_index_storage::<S>().0 as u8))
+                .expect("Attempted to find a component column that was not registered!");
+
+            self.query_aabb::<S>(Aabb::around(center, radius))
+                .into_iter()
+                .filter(|entity| read_position::<S>(column.as_ref(), *entity).is_some_and(|position| {
+                    let (x, y) = (position[0] - center[0], position[1] - center[1]);
+                    x * x + y * y <= radius * radius
+                })).collect()
+
+        } // fn query_radius()
+
+
+        pub fn modify_component<C: Component, R>(&mut self, entity: Entity, modify: impl FnOnce(&mut C) -> R) -> Option<R> {
+
+            let modified = self.get_component_column::<C>()
+                .get(&entity)
+                .map(|component| modify(&mut component.borrow_mut()))?;
+
+            self.update_component_indexes(self.component_id::<C>().index() as usize, entity);
+            self.flush_dirty_indexes();
+            Some(modified)
+
+        } // fn modify_component()
+
+
+        pub fn refresh_indexes(&mut self) {
+
+            let entities = self.get_entity_ids();
+            let indexed  = (0..self.components.len())
+                .filter(|index| self.is_indexed(*index))
+                .collect::<Vec<usize>>();
+
+            indexed
+                .into_iter()
+                .for_each(|index| entities
+                    .iter()
+                    .for_each(|entity| self.update_component_indexes(index, *entity)));
+
+        } // fn refresh_indexes()
+
+
+        fn is_indexed(&self, index: usize) -> bool {
+            (self.names.is_some() && self.components[index].type_id == Some(TypeId::of::<Name>()))
+                || self.indexes.iter().any(|(component, _, _)| *component == index)
+        } // fn is_indexed()
+
+
+        pub(crate) fn mark_indexes_dirty(&self, index: usize, entities: &[Entity]) {
+
+            if !self.is_indexed(index) { return }
+            self.dirty_indexes
+                .borrow_mut()
+                .entry(index)
+                .or_default()
+                .extend(entities.iter().copied());
+
+        } // fn mark_indexes_dirty()
+
+
+        fn get_index_candidates(&self, index: usize, indexed: Vec<Entity>) -> Vec<Entity> {
+
+            let dirty_indexes = self.dirty_indexes.borrow();
+            let Some(dirty)   = dirty_indexes.get(&index) else { return indexed };
+
+            indexed
+                .into_iter()
+                .filter(|entity| !dirty.contains(entity))
+                .chain(dirty.iter().copied())
+                .collect()
+
+        } // fn get_index_candidates()
+
+
+        fn flush_dirty_indexes(&mut self) {
+            std::mem::take(self.dirty_indexes.get_mut())
+                .into_iter()
+                .for_each(|(index, entities)| entities
+                    .into_iter()
+                    .for_each(|entity| self.update_component_indexes(index, entity)));
+        } // fn flush_dirty_indexes()
+
+
+        fn get_dependent_indices(&self, bit_mask: B) -> Vec<usize> {
+            self.requirements
+                .keys()
+                .copied()
+                .filter(|index| bit_mask.has_bits(B::bit(*index as u8)))
+                .collect()
+        } // fn get_dependent_indices()
+
+
+        pub fn single<C: Component>(&self) -> Result<(Entity, Ref<'_, C>), WorldError> {
+            self.single_cell::<C>()
+                .map(|(entity, component)| (entity, component.borrow()))
+        } // fn single()
+
+
+        pub fn single_mut<C: Component>(&mut self) -> Result<(Entity, RefMut<'_, C>), WorldError> {
+
+            let index = self.component_id::<C>().index() as usize;
+            if self.is_indexed(index) { return Err(WorldError::IndexedComponent(self.components[index].name.clone())) }
+
+            self.single_cell::<C>()
+                .map(|(entity, component)| (entity, component.borrow_mut()))
+
+        } // fn single_mut()
+
+
+        pub fn modify_single<C: Component, R>(&mut self, modify: impl FnOnce(&mut C) -> R) -> Result<R, WorldError> {
+
+            let (entity, _) = self.single_cell::<C>()?;
+            Ok(self.modify_component(entity, modify)
+                .expect("Attempted to modify a component that was not registered!"))
+
+        } // fn modify_single()
+
+
+        fn single_cell<C: Component>(&self) -> Result<(Entity, &Rc<RefCell<C>>), WorldError> {
+
+            let component_column = self.get_component_column::<C>();
+            let name             = || self.components[self.component_id::<C>().index() as usize].name.clone();
+
+            match component_column.len() {
+                0 => Err(WorldError::NoMatch(name())),
+                1 => Ok(component_column
+                    .iter()
+                    .map(|(entity, component)| (*entity, component))
+                    .next()
+                    .expect("Attempted to find a component in an empty column!")),
+                count => Err(WorldError::MultipleMatches {
+                    component: name(),
+                    count,
+                }), // => ..
+            } // match ..
+
+        } // fn single_cell()
+
+
+        pub(crate) fn remove_component_cell<C: Component>(&mut self, entity: Entity) -> Option<Rc<RefCell<C>>> {
+
+            let removed = self.get_component_column_mut::<C>().remove(&entity);
+            self.update_component_indexes(self.component_id::<C>().index() as usize, entity);
+            removed
+
+        } // fn remove_component_cell()
+
+
+        pub fn get_pointer_component<C: Component>(&self, id: P) -> &Rc<RefCell<C>> {
+            self.component_pointers
+                .get(&id)
+                .expect("Attempted to find a component pointer that was not registered!")
+                .as_any()
+                .downcast_ref::<Rc<RefCell<C>>>()
+                .expect("Failed to downcast a component!")
+        } // fn get_pointer_component()
+
+
+        pub(crate) fn get_entity_bit_mask(&self, entity: Entity) -> B {
+            *self.entities
+                .get(&entity)
+                .expect("Attempted to find an entity that was not registered!")
+        } // fn get_entity_bit_mask()
+
+
+        pub(crate) fn get_entities(&self, bit_mask_filter: B) -> Vec<Entity> {
+            self.entities
+                .iter()
+                .filter(|(_, bit_mask)| **bit_mask & bit_mask_filter == bit_mask_filter)
+                .map(|(entity, _)| *entity)
+                .collect()
+        } // fn get_entities()
+
+
+        pub fn delete_entity(&mut self, entity: Entity) {
+
+            let entity_mask = self.entities
+                .get(&entity)
+                .expect("Attempted to delete an entity that was not registered!");
+
+            self.component_columns
                 .iter_mut()
                 .filter(|(bit_mask, _)| *entity_mask & **bit_mask == **bit_mask)
                 .for_each(|(_, component_column)| {
@@ -472,25 +1447,923 @@
                 }); // iter_mut()
             
             self.entities.remove(&entity);
-            
+            self.remove_indexed_entity(entity);
+
         } // fn delete_entity()
 
 
-        pub fn delete_entity_group(&mut self, entity_group: &[Entity]) {
-            entity_group.iter()
-                .for_each(|entity| self.delete_entity(*entity));
-        } // fn delete_entity_group()
+        pub fn delete_entity_group(&mut self, entity_group: &[Entity]) {
+            entity_group.iter()
+                .for_each(|entity| self.delete_entity(*entity));
+        } // fn delete_entity_group()
+
+
+        pub fn describe_mask(&self, bit_mask: B) -> MaskDescription<B, F> {
+
+            let offset         = self.flag_offset();
+            let mut flags      = self.flags
+                .iter()
+                .map(|(flag, range)| (range.start, *flag, read_flag_variant(bit_mask, self.flag_range(*flag))))
+                .filter(|(_, _, variant)| *variant != B::MIN)
+                .collect::<Vec<(u8, F, B)>>();
+
+            let mut flag_types = self.flag_types
+                .values()
+                .filter_map(|flag_type| (flag_type.describe)(read_flag_index(bit_mask, flag_type.range.start + offset..flag_type.range.end + offset))
+                    .map(|variant| (flag_type.range.start, flag_type.name, variant)))
+                .collect::<Vec<(u8, &'static str, String)>>();
+
+            flags.sort_by_key(|(start, _, _)| *start);
+            flag_types.sort_by_key(|(start, _, _)| *start);
+
+            MaskDescription {
+                components: self.components
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| bit_mask.has_bits(B::bit(*index as u8)))
+                    .map(|(_, info)| info.name.clone())
+                    .collect(),
+                flags:      flags.into_iter().map(|(_, flag, variant)| (flag, variant)).collect(),
+                flag_types: flag_types.into_iter().map(|(_, name, variant)| (name, variant)).collect(),
+            } // MaskDescription
+        } // fn describe_mask()
+
+
+        pub fn inspect(&self, entity: Entity) -> Option<EntityInspection<B, F>> {
+
+            let bit_mask = *self.entities.get(&entity)?;
+
+            Some(EntityInspection {
+                id:         entity.id(),
+                bit_mask,
+                mask:       self.describe_mask(bit_mask),
+                components: self.components
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| bit_mask.has_bits(B::bit(*index as u8)))
+                    .filter_map(|(index, info)| info.debug
+                        .and_then(|debug| debug(self.component_columns.get(&B::bit(index as u8))?.as_ref(), entity))
+                        .map(|output| (info.name.clone(), output)))
+                    .collect(),
+            }) // EntityInspection
+        } // fn inspect()
+
+
+        pub fn stats(&self) -> WorldStats {
+            WorldStats {
+                entity_count:  self.entities.len(),
+                entity_memory: self.entities.len() * size_of::<(Entity, B)>(),
+                columns:       self.components
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, info)| self.component_columns
+                        .get(&B::bit(index as u8))
+                        .map(|column| ColumnStats {
+                            name:         info.name.clone(),
+                            entity_count: column.entity_count(),
+                            memory_usage: column.memory_usage(),
+                        })) // ColumnStats
+                    .collect(),
+            } // WorldStats
+        } // fn stats()
+
+
+        pub fn reflected_components(&self) -> Vec<ReflectedComponent> {
+            self.components
+                .iter()
+                .filter_map(|info| info.reflect
+                    .as_ref()
+                    .map(|reflect| ReflectedComponent {
+                        name:   info.name.clone(),
+                        fields: reflect.fields.clone(),
+                    })) // ReflectedComponent
+                .collect()
+        } // fn reflected_components()
+
+
+        pub fn reflect_component(&self, entity: Entity, name: &str) -> Option<Ref<'_, dyn Reflect>> {
+
+            let (index, info) = self.find_component_info(name)?;
+            (info.reflect.as_ref()?.get)(self.component_columns.get(&B::bit(index as u8))?.as_ref(), entity)
+
+        } // fn reflect_component()
+
+
+        pub fn reflect_component_mut(&self, entity: Entity, name: &str) -> Option<RefMut<'_, dyn Reflect>> {
+
+            let (index, info) = self.find_component_info(name)?;
+            assert!(!self.is_indexed(index), "Attempted to mutably reflect the indexed component {}, use World::modify_reflected_component instead!", info.name);
+            (info.reflect.as_ref()?.get_mut)(self.component_columns.get(&B::bit(index as u8))?.as_ref(), entity)
+
+        } // fn reflect_component_mut()
+
+
+        pub fn modify_reflected_component<R>(
+            &mut self,
+            entity: Entity,
+            name:   &str,
+            modify: impl FnOnce(&mut dyn Reflect) -> R,
+        ) -> Option<R> {
+
+            let (index, info) = self.find_component_info(name)?;
+            let modified      = modify(&mut *(info.reflect.as_ref()?.get_mut)(self.component_columns.get(&B::bit(index as u8))?.as_ref(), entity)?);
+
+            self.update_component_indexes(index, entity);
+            Some(modified)
+
+        } // fn modify_reflected_component()
+
+
+        pub fn reflect_entity(&self, entity: Entity) -> Vec<(&str, Ref<'_, dyn Reflect>)> {
+            self.components
+                .iter()
+                .enumerate()
+                .filter_map(|(index, info)| (info.reflect.as_ref()?.get)(self.component_columns.get(&B::bit(index as u8))?.as_ref(), entity)
+                    .map(|component| (info.name.as_str(), component)))
+                .collect()
+        } // fn reflect_entity()
+
+
+        fn find_component_info(&self, name: &str) -> Option<(usize, &ComponentInfo)> {
+            self.components
+                .iter()
+                .enumerate()
+                .find(|(_, info)| info.name == name || info.name.rsplit("::").next() == Some(name))
+        } // fn find_component_info()
+
+
+        pub fn component_id<C: Component>(&self) -> ComponentId {
+            self.components
+                .iter()
+                .position(|info| info.type_id == Some(TypeId::of::<C>()))
+                .map(|index| ComponentId(index as u8))
+                .expect("Attempted to get a component ID that was not registered!")
+        } // fn component_id()
+
+
+        pub fn get_by_id(&self, entity: Entity, id: ComponentId) -> Option<Ptr<'_>> {
+
+            let component = self.component_columns
+                .get(&B::bit(id.0))?
+                .get_ptr(entity)?;
+
+            self.mark_indexes_dirty(id.0 as usize, &[entity]);
+            Some(component)
+
+        } // fn get_by_id()
+
+
+        pub fn component_id_by_name(&self, name: &str) -> Option<ComponentId> {
+            self.find_component_info(name)
+                .map(|(index, _)| ComponentId(index as u8))
+        } // fn component_id_by_name()
+
+
+        pub fn get_dynamic_component_layout(&self, id: ComponentId) -> Option<&ComponentLayout> {
+            self.get_dynamic_column(id)
+                .ok()
+                .map(|column| &column.layout)
+        } // fn get_dynamic_component_layout()
+
+
+        pub(crate) fn get_dynamic_column(&self, id: ComponentId) -> Result<&DynamicColumn, DynamicError> {
+            self.components
+                .get(id.0 as usize)
+                .and_then(|_| self.component_columns.get(&B::bit(id.0)))
+                .and_then(|column| column.as_any().downcast_ref::<DynamicColumn>())
+                .ok_or(DynamicError::UnknownComponent(id))
+        } // fn get_dynamic_column()
+
+
+        pub(crate) fn get_dynamic_column_mut(&mut self, id: ComponentId) -> Result<&mut DynamicColumn, DynamicError> {
+
+            if id.0 as usize >= self.components.len() { return Err(DynamicError::UnknownComponent(id)) }
+
+            self.component_columns
+                .get_mut(&B::bit(id.0))
+                .and_then(|column| column.as_any_mut().downcast_mut::<DynamicColumn>())
+                .ok_or(DynamicError::UnknownComponent(id))
+
+        } // fn get_dynamic_column_mut()
+
+
+        pub(crate) fn add_dynamic_component_to_entity_builder(
+            &mut self,
+            id:              ComponentId,
+            component:       DynamicComponent,
+            entity:          Entity,
+            entity_bit_mask: &mut B,
+        ) -> Result<(), DynamicError> {
+
+            let column = self.get_dynamic_column_mut(id)?;
+            match column.layout.matches(&component) {
+                true  => column.insert_value(entity, component),
+                false => { return Err(DynamicError::LayoutMismatch(id)) },
+            } // match ..
+
+            *entity_bit_mask |= B::bit(id.0);
+            Ok(())
+
+        } // fn add_dynamic_component_to_entity_builder()
+
+
+        pub fn set_dynamic_component(
+            &mut self,
+            entity:    Entity,
+            id:        ComponentId,
+            component: DynamicComponent,
+        ) -> Result<(), DynamicError> {
+
+            let mut entity_bit_mask = self.get_entity_bit_mask(entity);
+            self.add_dynamic_component_to_entity_builder(id, component, entity, &mut entity_bit_mask)?;
+            self.entities.insert(entity, entity_bit_mask);
+
+            Ok(())
+
+        } // fn set_dynamic_component()
+
+
+        pub fn get_dynamic_component(&self, entity: Entity, id: ComponentId) -> Option<&DynamicComponent> {
+            self.get_dynamic_column(id)
+                .ok()?
+                .values
+                .get(&entity)
+        } // fn get_dynamic_component()
+
+
+        pub fn get_dynamic_bytes_mut(&mut self, entity: Entity, id: ComponentId) -> Option<&mut [u8]> {
+            match self.get_dynamic_column_mut(id).ok()?.values.get_mut(&entity)? {
+                DynamicComponent::Bytes(bytes) => Some(bytes.as_mut_slice()),
+                DynamicComponent::Map(_)       => None,
+            } // match ..
+        } // fn get_dynamic_bytes_mut()
+
+
+        pub fn remove_dynamic_component(&mut self, entity: Entity, id: ComponentId) -> Option<DynamicComponent> {
+
+            let component = self.get_dynamic_column_mut(id)
+                .ok()?
+                .values
+                .remove(&entity);
+
+            *self.entities
+                .get_mut(&entity)
+                .expect("Attempted to find an entity that was not registered!") &= !B::bit(id.0);
+
+            component
+
+        } // fn remove_dynamic_component()
+
+
+        pub fn clone_entity(&mut self, entity: Entity) -> Entity { self.clone_entity_with(entity, SharedCloning::Shallow) }
+        pub fn try_clone_entity(&mut self, entity: Entity) -> Result<Entity, WorldError> { self.try_clone_entity_with(entity, SharedCloning::Shallow) }
+
+
+        pub fn clone_entity_with(&mut self, entity: Entity, cloning: SharedCloning) -> Entity {
+            self.try_clone_entity_with(entity, cloning)
+                .unwrap_or_else(|error| panic!("{}", error))
+        } // fn clone_entity_with()
+
+
+        pub fn try_clone_entity_with(&mut self, entity: Entity, cloning: SharedCloning) -> Result<Entity, WorldError> {
+
+            let bit_mask   = self.get_entity_bit_mask(entity);
+            let components = self.clone_entity_components(entity, bit_mask, cloning);
+
+            components
+                .iter()
+                .try_for_each(|(index, component)| {
+                    self.check_unique_component(*index, &[])?;
+                    self.validate_boxed(*index, Entity::new(self.next_entity_id), component.as_ref())
+                })?; // try_for_each()
+
+            let clone = self.next_entity();
+            components
+                .into_iter()
+                .for_each(|(index, component)| self.insert_boxed_component(index, clone, component));
+
+            self.entities.insert(clone, bit_mask);
+            Ok(clone)
+
+        } // fn try_clone_entity_with()
+
+
+        pub fn copy_entity_to(&self, other: &mut World<B, F, P>, entity: Entity) -> Entity { self.copy_entity_to_with(other, entity, SharedCloning::Shallow) }
+        pub fn try_copy_entity_to(&self, other: &mut World<B, F, P>, entity: Entity) -> Result<Entity, WorldError> { self.try_copy_entity_to_with(other, entity, SharedCloning::Shallow) }
+
+
+        pub fn copy_entity_to_with(
+            &self,
+            other:   &mut World<B, F, P>,
+            entity:  Entity,
+            cloning: SharedCloning,
+        ) -> Entity {
+            self.try_copy_entity_to_with(other, entity, cloning)
+                .unwrap_or_else(|error| panic!("{}", error))
+        } // fn copy_entity_to_with()
+
+
+        pub fn try_copy_entity_to_with(
+            &self,
+            other:   &mut World<B, F, P>,
+            entity:  Entity,
+            cloning: SharedCloning,
+        ) -> Result<Entity, WorldError> {
+
+            let bit_mask   = self.get_entity_bit_mask(entity);
+            let components = self.clone_entity_components(entity, bit_mask, cloning)
+                .into_iter()
+                .map(|(index, component)| {
+
+                    let info        = &self.components[index];
+                    let other_index = other.components
+                        .iter()
+                        .position(|other_info| match info.type_id {
+                            Some(type_id) => other_info.type_id == Some(type_id),
+                            None          => other_info.type_id.is_none() && other_info.name == info.name,
+                        }).ok_or_else(|| WorldError::UnregisteredComponent(info.name.clone()))?;
+
+                    other.check_unique_component(other_index, &[])?;
+                    other.validate_boxed(other_index, Entity::new(other.next_entity_id), component.as_ref())?;
+                    Ok((other_index, component))
+
+                }).collect::<Result<BoxedComponents, WorldError>>()?;
+
+            let copy          = other.next_entity();
+            let mut copy_mask = B::MIN;
+
+            components
+                .into_iter()
+                .for_each(|(index, component)| {
+                    copy_mask |= B::bit(index as u8);
+                    other.insert_boxed_component(index, copy, component);
+                }); // for_each()
+
+            self.flags
+                .keys()
+                .filter(|flag| other.flags.contains_key(flag))
+                .for_each(|flag| {
+                    let variant = read_flag_variant(bit_mask, self.flag_range(*flag));
+                    if variant != B::MIN { copy_mask |= other.flag_bit_mask(*flag, Some(variant)) }
+                }); // for_each()
+
+            self.flag_types
+                .iter()
+                .filter_map(|(id, flag_type)| Some((flag_type, other.flag_types.get(id)?)))
+                .for_each(|(flag_type, other_flag_type)| {
+                    let index = read_flag_index(bit_mask, self.flag_offset() + flag_type.range.start..self.flag_offset() + flag_type.range.end);
+                    copy_mask |= flag_index_bit_mask(other.flag_offset() + other_flag_type.range.start..other.flag_offset() + other_flag_type.range.end, index);
+                }); // for_each()
+
+            other.entities.insert(copy, copy_mask);
+            Ok(copy)
+
+        } // fn try_copy_entity_to_with()
+
+
+        fn clone_entity_components(
+            &self,
+            entity:   Entity,
+            bit_mask: B,
+            cloning:  SharedCloning,
+        ) -> Vec<(usize, Box<dyn Any>)> {
+            (0..self.components.len())
+                .filter(|index| bit_mask.has_bits(B::bit(*index as u8)))
+                .filter_map(|index| self.component_columns
+                    .get(&B::bit(index as u8))?
+                    .clone_component(entity, cloning)
+                    .map(|component| (index, component)))
+                .collect()
+        } // fn clone_entity_components()
+
+
+        pub(crate) fn insert_boxed_component(
+            &mut self,
+            index:     usize,
+            entity:    Entity,
+            component: Box<dyn Any>,
+        ) { self.try_insert_boxed_component(index, entity, component).unwrap_or_else(|error| panic!("{}", error)) }
+
+
+        pub(crate) fn try_insert_boxed_component(
+            &mut self,
+            index:     usize,
+            entity:    Entity,
+            component: Box<dyn Any>,
+        ) -> Result<(), WorldError> {
+
+            self.check_unique_component(index, &[entity])?;
+            self.validate_boxed(index, entity, component.as_ref())?;
+
+            self.component_columns
+                .get_mut(&B::bit(index as u8))
+                .expect("Attempted to find a component column that was not registered!")
+                .insert_component(entity, component);
+
+            self.update_component_indexes(index, entity);
+            Ok(())
+
+        } // fn try_insert_boxed_component()
+
+
+        pub(crate) fn take_entity(&mut self, entity: Entity) -> (B, BoxedComponents) {
+
+            let bit_mask = self.entities
+                .remove(&entity)
+                .expect("Attempted to take an entity that was not registered!");
+            self.remove_indexed_entity(entity);
+
+            let components = (0..self.components.len())
+                .filter(|index| bit_mask.has_bits(B::bit(*index as u8)))
+                .filter_map(|index| self.component_columns
+                    .get_mut(&B::bit(index as u8))?
+                    .take_component(entity)
+                    .map(|component| (index, component)))
+                .collect();
+
+            (bit_mask, components)
+
+        } // fn take_entity()
+
+
+        pub(crate) fn insert_entity(
+            &mut self,
+            bit_mask:   B,
+            components: BoxedComponents,
+        ) -> Entity {
+
+            let entity = self.next_entity();
+            components
+                .into_iter()
+                .for_each(|(index, component)| self.insert_boxed_component(index, entity, component));
+
+            self.entities.insert(entity, bit_mask);
+            entity
+
+        } // fn insert_entity()
+
+
+        pub(crate) fn get_entity_ids(&self) -> Vec<Entity> { self.entities.keys().copied().collect() }
+
+
+        pub fn checksum(&self) -> u64 {
+
+            let mut hasher = StableHasher::new();
+            self.entities
+                .iter()
+                .for_each(|(entity, bit_mask)| {
+
+                    entity.hash(&mut hasher);
+                    bit_mask.hash(&mut hasher);
+
+                    self.components
+                        .iter()
+                        .enumerate()
+                        .filter(|(index, _)| bit_mask.has_bits(B::bit(*index as u8)))
+                        .filter_map(|(index, info)| Some((index, info.checksum?, self.component_columns.get(&B::bit(index as u8))?)))
+                        .for_each(|(index, checksum, column)| {
+                            hasher.write_usize(index);
+                            checksum(column.as_ref(), *entity, &mut hasher);
+                        }); // for_each()
+
+                }); // for_each()
+
+            hasher.finish()
+
+        } // fn checksum()
 
 
-        pub fn new_entity(&mut self) -> EntityBuilder<B, F, P> {
+        pub fn snapshot(&self) -> WorldSnapshot<B> { self.snapshot_with(None) }
+        pub fn snapshot_from(&self, base: &WorldSnapshot<B>) -> WorldSnapshot<B> { self.snapshot_with(Some(base)) }
+
+
+        fn snapshot_with(&self, base: Option<&WorldSnapshot<B>>) -> WorldSnapshot<B> {
+            WorldSnapshot {
+                entities:       self.entities.clone(),
+                next_entity_id: self.next_entity_id,
+                columns:        self.components
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, info)| {
+
+                        let snapshot = info.rollback.or(info.replication.map(|replication| replication.snapshot))?;
+                        let column   = self.component_columns.get(&B::bit(index as u8))?;
+                        let base     = base
+                            .and_then(|base| base.columns.iter().find(|(column, _)| *column == index))
+                            .map(|(_, column)| column.as_ref() as &dyn ColumnSnapshot);
+
+                        Some((index, snapshot(column.as_ref(), base)))
+
+                    }).collect(),
+            } // WorldSnapshot
+        } // fn snapshot_with()
+
+
+        pub fn restore(&mut self, snapshot: &WorldSnapshot<B>) {
+
+            self.get_entity_ids()
+                .into_iter()
+                .filter(|entity| !snapshot.contains_entity(*entity))
+                .for_each(|entity| self.delete_entity(entity));
+
+            snapshot.columns
+                .iter()
+                .filter(|(index, _)| self.components[*index].rollback.is_some())
+                .for_each(|(index, column)| column.restore(self.component_columns
+                    .get_mut(&B::bit(*index as u8))
+                    .expect("Attempted to find a component column that was not registered!")
+                    .as_mut()));
+
+            let kept = (0..self.components.len())
+                .filter(|index| self.components[*index].rollback.is_none())
+                .fold(B::MIN, |bit_mask, index| bit_mask | B::bit(index as u8));
+
+            let live      = std::mem::take(&mut self.entities);
+            self.entities = snapshot.entities
+                .iter()
+                .map(|(entity, bit_mask)| (*entity, (*bit_mask & !kept) | (live.get(entity).copied().unwrap_or(B::MIN) & kept)))
+                .map(|(entity, bit_mask)| (entity, (0..self.components.len())
+                    .filter(|index| bit_mask.has_bits(B::bit(*index as u8)))
+                    .filter(|index| self.component_columns
+                        .get(&B::bit(*index as u8))
+                        .is_some_and(|column| column.get_ptr(entity).is_none()))
+                    .fold(bit_mask, |bit_mask, index| bit_mask & !B::bit(index as u8))))
+                .collect();
+
+            self.next_entity_id = snapshot.next_entity_id;
+            self.refresh_indexes();
+
+        } // fn restore()
+
+
+        pub fn diff(&self, previous: &WorldSnapshot<B>) -> WorldDelta<B> {
+
+            let replicated = self.replicated_bit_mask();
+            let known      = previous.entities
+                .iter()
+                .map(|(entity, bit_mask)| (*entity, *bit_mask & replicated))
+                .collect::<BTreeMap<Entity, B>>();
+
+            self.diff_with(&self.diff_columns(previous), &known, |_| true, |_| true)
+
+        } // fn diff()
+
+
+        pub(crate) fn diff_columns(&self, previous: &WorldSnapshot<B>) -> ColumnChanges {
+            self.components
+                .iter()
+                .enumerate()
+                .filter_map(|(index, info)| Some((index, info.replication?)))
+                .map(|(index, replication)| {
+
+                    let column = self.component_columns
+                        .get(&B::bit(index as u8))
+                        .expect("Attempted to find a component column that was not registered!");
+
+                    let previous = match replication.rule {
+                        ReplicationRule::Always => None,
+                        _                       => previous.columns
+                            .iter()
+                            .find(|(column, _)| *column == index)
+                            .map(|(_, column)| column.as_ref() as &dyn ColumnSnapshot),
+                    }; // let previous
+
+                    (index, (replication.diff)(column.as_ref(), previous)
+                        .into_iter()
+                        .collect())
+
+                }).collect()
+        } // fn diff_columns()
+
+
+        pub(crate) fn diff_with(
+            &self,
+            changes:  &ColumnChanges,
+            known:    &BTreeMap<Entity, B>,
+            visible:  impl Fn(Entity) -> bool,
+            owned:    impl Fn(Entity) -> bool,
+        ) -> WorldDelta<B> {
+
+            let replicated = self.replicated_bit_mask();
+            let entities   = self.entities
+                .iter()
+                .filter(|(entity, _)| visible(**entity))
+                .map(|(entity, bit_mask)| (*entity, self.components
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, info)| info.replication.is_some_and(|replication| replication.rule == ReplicationRule::OwnerOnly))
+                    .filter(|_| !owned(*entity))
+                    .fold(*bit_mask & replicated, |bit_mask, (index, _)| bit_mask & !B::bit(index as u8))))
+                .collect::<Vec<(Entity, B)>>();
+
+            let spawned = entities
+                .iter()
+                .filter(|(entity, _)| !known.contains_key(entity))
+                .copied()
+                .collect();
+
+            let masks = entities
+                .iter()
+                .filter(|(entity, bit_mask)| known.get(entity).is_some_and(|known| known != bit_mask))
+                .copied()
+                .collect();
+
+            let despawned = known
+                .keys()
+                .filter(|entity| entities.binary_search_by_key(*entity, |(entity, _)| *entity).is_err())
+                .copied()
+                .collect();
+
+            let components = changes
+                .iter()
+                .flat_map(|(index, changed)| {
+
+                    let replication = self.components[*index].replication
+                        .expect("Attempted to diff a component that is not replicated!");
+
+                    let column = self.component_columns
+                        .get(&B::bit(*index as u8))
+                        .expect("Attempted to find a component column that was not registered!");
+
+                    entities
+                        .iter()
+                        .filter(|(_, bit_mask)| bit_mask.has_bits(B::bit(*index as u8)))
+                        .filter_map(|(entity, _)| match changed.get(entity) {
+                            Some(component) => Some(component.clone()),
+                            None            => match known.get(entity).is_some_and(|known| known.has_bits(B::bit(*index as u8))) {
+                                true  => None,
+                                false => (replication.read)(column.as_ref(), *entity),
+                            }, // None
+                        }.map(|component| (*index, *entity, component)))
+                        .collect::<Vec<(usize, Entity, Vec<u8>)>>()
+
+                }).collect();
+
+            WorldDelta {
+                spawned,
+                despawned,
+                masks,
+                components,
+                full: false,
+            } // WorldDelta
+
+        } // fn diff_with()
+
+
+        pub fn apply_delta(&mut self, delta: &WorldDelta<B>, entity_map: &mut EntityMap) -> Result<(), WorldError> {
+
+            if delta.full {
+
+                let stale = entity_map
+                    .get_server_entities()
+                    .into_iter()
+                    .filter(|entity| !delta.spawned.iter().any(|(spawned, _)| spawned == entity) && !delta.despawned.contains(entity))
+                    .collect::<Vec<Entity>>();
+
+                let mut delta = delta.clone();
+                delta.full    = false;
+                delta.despawned.extend(stale);
+                return self.apply_delta(&delta, entity_map)
+
+            } // if ..
+
+            let replicated = self.replicated_bit_mask();
+            let components = self.check_delta(delta, entity_map, replicated)?;
+
+            delta.despawned
+                .iter()
+                .filter_map(|entity| entity_map.remove(*entity))
+                .filter(|entity| self.contains_entity(*entity))
+                .collect::<Vec<Entity>>()
+                .into_iter()
+                .for_each(|entity| self.delete_entity(entity));
+
+            delta.spawned
+                .iter()
+                .for_each(|(entity, _)| if !entity_map.get_local_entity(*entity).is_some_and(|local| self.contains_entity(local)) {
+                    let local = self.next_entity();
+                    self.entities.insert(local, B::MIN);
+                    entity_map.insert(*entity, local);
+                }); // for_each()
+
+            delta.spawned
+                .iter()
+                .chain(delta.masks.iter())
+                .for_each(|(entity, bit_mask)| {
+
+                    let local = entity_map
+                        .get_local_entity(*entity)
+                        .expect("Attempted to apply a mask to an entity that was never replicated!");
+
+                    self.apply_replicated_mask(local, *bit_mask, replicated);
+
+                }); // for_each()
+
+            components
+                .into_iter()
+                .try_for_each(|(index, entity, component)| {
+
+                    let local = entity_map
+                        .get_local_entity(entity)
+                        .expect("Attempted to apply a component to an entity that was never replicated!");
+
+                    self.try_insert_boxed_component(index, local, component)
+
+                }) // try_for_each()
+
+        } // fn apply_delta()
+
+
+        fn check_delta(
+            &self,
+            delta:      &WorldDelta<B>,
+            entity_map: &EntityMap,
+            replicated: B,
+        ) -> Result<DecodedComponents, WorldError> {
+
+            if let Some(entity) = delta.despawned.iter().find(|entity| entity_map.get_local_entity(**entity).is_none()) {
+                return Err(WorldError::UnknownEntity(*entity))
+            } // if ..
+
+            let spawned = delta.spawned
+                .iter()
+                .map(|(entity, _)| *entity)
+                .collect::<HashSet<Entity>>();
+
+            let current = |entity: Entity| entity_map
+                .get_local_entity(entity)
+                .filter(|_| !delta.despawned.contains(&entity))
+                .and_then(|local| self.entities.get(&local).copied());
+
+            let masks = delta.spawned
+                .iter()
+                .chain(delta.masks.iter())
+                .map(|(entity, bit_mask)| match spawned.contains(entity) || current(*entity).is_some() {
+                    true if *bit_mask & !replicated != B::MIN => Err(WorldError::MalformedDelta(format!("the mask of the entity no.{} holds unreplicated bits", entity.id()))),
+                    true                                      => Ok((*entity, *bit_mask)),
+                    false                                     => Err(WorldError::UnknownEntity(*entity)),
+                }).collect::<Result<HashMap<Entity, B>, WorldError>>()?;
+
+            let components = delta.components
+                .iter()
+                .map(|(index, entity, bytes)| {
+
+                    let info = self.components
+                        .get(*index)
+                        .filter(|info| info.replication.is_some())
+                        .ok_or_else(|| WorldError::MalformedDelta(format!("the component no.{} is not replicated", index)))?;
+
+                    match masks.get(entity).copied().or_else(|| current(*entity)) {
+                        None                                                         => return Err(WorldError::UnknownEntity(*entity)),
+                        Some(bit_mask) if !bit_mask.has_bits(B::bit(*index as u8)) => return Err(WorldError::MalformedDelta(format!("the entity no.{} does not hold the component {}", entity.id(), info.name))),
+                        Some(_)                                                      => (),
+                    } // match ..
+
+                    let component = info.replication
+                        .and_then(|replication| (replication.decode)(bytes))
+                        .ok_or_else(|| WorldError::MalformedDelta(format!("failed to decode the component {}", info.name)))?;
+
+                    Ok((*index, *entity, component))
+
+                }).collect::<Result<DecodedComponents, WorldError>>()?;
+
+            let provided = components
+                .iter()
+                .map(|(index, entity, _)| (*index, *entity))
+                .collect::<HashSet<(usize, Entity)>>();
+
+            if let Some((index, entity)) = masks
+                .iter()
+                .flat_map(|(entity, bit_mask)| (0..self.components.len())
+                    .filter(|index| bit_mask.has_bits(B::bit(*index as u8)))
+                    .filter(|index| !current(*entity).is_some_and(|current| current.has_bits(B::bit(*index as u8))))
+                    .map(|index| (index, *entity)))
+                .find(|added| !provided.contains(added)) {
+                return Err(WorldError::MalformedDelta(format!("the component {} of the entity no.{} is missing", self.components[index].name, entity.id())))
+            } // if ..
+
+            let mut next_entity_id = self.next_entity_id;
+            let locals             = delta.spawned
+                .iter()
+                .chain(delta.masks.iter())
+                .map(|(entity, _)| *entity)
+                .fold(HashMap::new(), |mut locals, entity| {
+                    locals.entry(entity).or_insert_with(|| entity_map
+                        .get_local_entity(entity)
+                        .filter(|local| !delta.despawned.contains(&entity) && self.contains_entity(*local))
+                        .unwrap_or_else(|| { next_entity_id += 1; Entity::new(next_entity_id - 1) }));
+                    locals
+                }); // fold()
+
+            let local = |entity: &Entity| locals
+                .get(entity)
+                .copied()
+                .or_else(|| entity_map.get_local_entity(*entity))
+                .expect("Attempted to find an entity that was never replicated!");
+
+            components
+                .iter()
+                .try_for_each(|(index, entity, component)| self.validate_boxed(*index, local(entity), component.as_ref()))?;
+
+            self.components
+                .iter()
+                .enumerate()
+                .filter(|(_, info)| info.unique)
+                .try_for_each(|(index, info)| {
+
+                    let receivers = components
+                        .iter()
+                        .filter(|(component, _, _)| *component == index)
+                        .map(|(_, entity, _)| local(entity))
+                        .collect::<Vec<Entity>>();
+
+                    if receivers.is_empty() { return Ok(()) }
+
+                    let holder = self.component_columns
+                        .get(&B::bit(index as u8))
+                        .expect("Attempted to find a component column that was not registered!")
+                        .get_entities()
+                        .into_iter()
+                        .filter(|holder| !receivers.contains(holder))
+                        .find(|holder| entity_map
+                            .get_server_entity(*holder)
+                            .is_none_or(|server| !delta.despawned.contains(&server)
+                                && masks.get(&server).is_none_or(|bit_mask| bit_mask.has_bits(B::bit(index as u8)))))
+                        .or_else(|| receivers
+                            .iter()
+                            .copied()
+                            .find(|receiver| *receiver != receivers[0]));
+
+                    match holder {
+                        Some(holder) => Err(WorldError::UniqueViolation {
+                            component: info.name.clone(),
+                            holder,
+                        }), // => ..
+                        None => Ok(()),
+                    } // match ..
+
+                })?; // try_for_each()
+
+            Ok(components)
+
+        } // fn check_delta()
+
+
+        fn apply_replicated_mask(&mut self, entity: Entity, bit_mask: B, replicated: B) {
+
+            let previous = *self.entities
+                .get(&entity)
+                .expect("Attempted to find an entity that was not registered!");
+
+            self.entities.insert(entity, (previous & !replicated) | bit_mask);
+
+            (0..self.components.len())
+                .filter(|index| previous.has_bits(B::bit(*index as u8)) && !bit_mask.has_bits(B::bit(*index as u8)))
+                .filter(|index| replicated.has_bits(B::bit(*index as u8)))
+                .for_each(|index| {
+                    self.component_columns
+                        .get_mut(&B::bit(index as u8))
+                        .expect("Attempted to find a component column that was not registered!")
+                        .remove_entity(entity);
+                    self.update_component_indexes(index, entity);
+                }); // for_each()
+
+        } // fn apply_replicated_mask()
+
+
+        fn replicated_bit_mask(&self) -> B {
+            self.components
+                .iter()
+                .enumerate()
+                .filter(|(_, info)| info.replication.is_some())
+                .fold(B::bit_mask(self.flag_offset()..B::BITS), |bit_mask, (index, _)| bit_mask | B::bit(index as u8))
+        } // fn replicated_bit_mask()
+
+
+        pub(crate) fn next_entity(&mut self) -> Entity {
 
             self.next_entity_id += 1;
-            EntityBuilder::new(self.next_entity_id - 1, self)
+            Entity::new(self.next_entity_id - 1)
+
+        } // fn next_entity()
+
+
+        pub fn new_entity(&mut self) -> EntityBuilder<B, F, P> {
+
+            let entity = self.next_entity();
+            EntityBuilder::new(entity.id(), self)
 
         } // fn new_entity()
 
 
-        pub const fn new_query(&self) -> QueryBuilder<B, F, P> { QueryBuilder { bit_mask: B::MIN, world: self }}
+        pub const fn new_query(&self) -> QueryBuilder<B, F, P> {
+            QueryBuilder {
+                bit_mask:     B::MIN,
+                flag_filters: Vec::new(),
+                candidates:   None,
+                world:        self,
+            } // QueryBuilder
+        } // fn new_query()
 
     } // impl World
 
@@ -522,23 +2395,212 @@
         } // fn with_shared_component_pointer()
 
 
-        pub fn with_component<C: Component>(mut self) -> Self {
+        pub fn with_component<C: Component>(self) -> Self { self.with_component_info::<C>(ComponentInfo::of::<C>()) }
 
-            match self.components.contains(&TypeId::of::<C>()) {
-                true =>  { println!("The component no.{} has been discarded as it was already registered!", self.component_count ) },
-                false => {
-                    self.components.push(TypeId::of::<C>());
+
+        pub fn with_debug_component<C: Component + Debug>(self) -> Self { self.with_component_info::<C>(ComponentInfo::of::<C>().with_debug::<C>()) }
+
+
+        pub fn with_reflect_component<C: Component + Reflect>(self) -> Self { self.with_component_info::<C>(ComponentInfo::of::<C>().with_reflect::<C>()) }
+
+
+        pub fn with_names(mut self, policy: NamePolicy) -> Self {
+
+            self.register_component::<Name>();
+            self.names = Some(NameIndex::new(policy));
+            self
+
+        } // fn with_names()
+
+
+        pub fn with_hash_index<I: ComponentIndex>(self) -> Self where I::Key: Hash { self.with_index::<I::Component, I>(Box::new(HashIndex::<I>::new())) }
+
+
+        pub fn with_ordered_index<I: ComponentIndex>(self) -> Self where I::Key: Ord { self.with_index::<I::Component, I>(Box::new(OrderedIndex::<I>::new())) }
+
+
+        #[cfg(feature = "spatial")]
+        pub fn with_spatial_grid<S: SpatialIndex>(self, cell_size: f32) -> Self { self.with_index::<S::Component, S>(Box::new(GridIndex::<S>::new(cell_size))) }
+
+
+        #[cfg(feature = "spatial")]
+        pub fn with_spatial_quadtree<S: SpatialIndex>(self, bounds: Aabb, capacity: usize) -> Self {
+            self.with_index::<S::Component, S>(Box::new(QuadTreeIndex::<S>::new(bounds, capacity)))
+        } // fn with_spatial_quadtree()
+
+
+        fn with_index<C: Component, I: 'static>(mut self, storage: Box<dyn IndexStorage>) -> Self {
+
+            let index = self.components
+                .iter()
+                .position(|info| info.type_id == Some(TypeId::of::<C>()))
+                .expect("Attempted to index a component that was not registered!");
+
+            match self.indexes.iter().any(|(_, id, _)| *id == TypeId::of::<I>()) {
+                true  => println!("The index {} has been discarded as it was already registered!", std::any::type_name::<I>()),
+                false => self.indexes.push((index, TypeId::of::<I>(), storage)),
+            } // match ..
+
+            self
+
+        } // fn with_index()
+
+
+        pub fn with_unique<C: Component>(mut self) -> Self {
+
+            self.components
+                .iter_mut()
+                .find(|info| info.type_id == Some(TypeId::of::<C>()))
+                .expect("Attempted to make unique a component that was not registered!")
+                .unique = true;
+
+            self
+
+        } // fn with_unique()
+
+
+        pub fn with_checksum<C: Component + Hash>(mut self) -> Self {
+
+            self.components
+                .iter_mut()
+                .find(|info| info.type_id == Some(TypeId::of::<C>()))
+                .expect("Attempted to checksum a component that was not registered!")
+                .checksum = Some(checksum_component::<C>);
+
+            self
+
+        } // fn with_checksum()
+
+
+        pub fn with_rollback<C: Component + PartialEq>(mut self) -> Self {
+
+            self.components
+                .iter_mut()
+                .find(|info| info.type_id == Some(TypeId::of::<C>()))
+                .expect("Attempted to roll back a component that was not registered!")
+                .rollback = Some(snapshot_column::<C>);
+
+            self
+
+        } // fn with_rollback()
+
+
+        pub fn with_replicated<C: Replicate>(self) -> Self { self.with_replication::<C>(ReplicationRule::default()) }
+
+
+        pub fn with_replication<C: Replicate>(mut self, rule: ReplicationRule) -> Self {
+
+            self.components
+                .iter_mut()
+                .find(|info| info.type_id == Some(TypeId::of::<C>()))
+                .expect("Attempted to replicate a component that was not registered!")
+                .replication = Some(ReplicationInfo::of::<C>(rule));
+
+            self
+
+        } // fn with_replication()
+
+
+        pub fn with_validator<C: Component>(mut self, validator: impl Fn(&C) -> Result<(), String> + 'static) -> Self {
+
+            self.components
+                .iter_mut()
+                .find(|info| info.type_id == Some(TypeId::of::<C>()))
+                .expect("Attempted to add a validator to a component that was not registered!")
+                .add_validator(validator);
+
+            self
+
+        } // fn with_validator()
+
+
+        pub fn with_required<C: Component, R: Bundle + Default>(mut self) -> Self {
+
+            let index = self.components
+                .iter()
+                .position(|info| info.type_id == Some(TypeId::of::<C>()))
+                .expect("Attempted to require components for a component that was not registered!");
+
+            self.requirements
+                .entry(index)
+                .or_default()
+                .push(Requirement::of::<R>());
+
+            self
+
+        } // fn with_required()
+
+
+        pub fn register_component<C: Component>(&mut self) -> ComponentId { self.register_component_info::<C>(ComponentInfo::of::<C>()) }
+
+
+        pub(crate) fn component_count(&self) -> usize { self.component_count }
+
+
+        pub fn with_dynamic_component(mut self, name: &str, layout: ComponentLayout) -> Self {
+
+            self.register_dynamic_component(name, layout);
+            self
+
+        } // fn with_dynamic_component()
+
+
+        pub fn register_dynamic_component(&mut self, name: &str, layout: ComponentLayout) -> ComponentId {
+
+            match self.components.iter().position(|info| info.type_id.is_none() && info.name == name) {
+                Some(index) => {
+
+                    println!("The dynamic component {} has been discarded as it was already registered!", name);
+                    ComponentId(index as u8)
+
+                }, // Some
+                None => {
+
+                    self.components.push(ComponentInfo::dynamic(name));
                     self.component_columns.insert(
                         B::bit(self.component_count as u8),
-                        Box::new(HashMap::<Entity, Rc<RefCell<C>>>::new()
-                    )); // insert()
+                        Box::new(DynamicColumn::new(layout)),
+                    ); // insert()
+
                     self.component_count += 1;
-                }, // false
+                    ComponentId(self.component_count as u8 - 1u8)
+
+                }, // None
             } // match ..
+        } // fn register_dynamic_component()
 
+
+        fn with_component_info<C: Component>(mut self, info: ComponentInfo) -> Self {
+
+            self.register_component_info::<C>(info);
             self
 
-        } // fn with_component()
+        } // fn with_component_info()
+
+
+        fn register_component_info<C: Component>(&mut self, info: ComponentInfo) -> ComponentId {
+
+            match self.components.iter().position(|info| info.type_id == Some(TypeId::of::<C>())) {
+                Some(index) => {
+
+                    println!("The component no.{} has been discarded as it was already registered!", index);
+                    ComponentId(index as u8)
+
+                }, // Some
+                None => {
+
+                    self.components.push(info);
+                    self.component_columns.insert(
+                        B::bit(self.component_count as u8),
+                        Box::new(HashMap::<Entity, Rc<RefCell<C>>>::new()
+                    )); // insert()
+
+                    self.component_count += 1;
+                    ComponentId(self.component_count as u8 - 1u8)
+
+                }, // None
+            } // match ..
+        } // fn register_component_info()
 
 
         pub fn with_flag<T: Into<F>>(mut self, flag: T, range: Range<u8>) -> Self {
@@ -549,25 +2611,91 @@
         } // fn with_flag()
 
 
+        pub fn with_flag_type<T: Flag>(mut self) -> Self {
+
+            match self.flag_types.iter().any(|(id, _)| id == &TypeId::of::<T>()) {
+                true  => { println!("The flag type {} has been discarded as it was already registered!", std::any::type_name::<T>()) },
+                false => {
+
+                    assert!(
+                        T::VARIANTS.len() <= 256usize,
+                        "WARNING: flag type {} has too many variants!", std::any::type_name::<T>()
+                    );
+
+                    self.flag_types.push((TypeId::of::<T>(), FlagType::of::<T>()));
+
+                }, // false
+            } // match ..
+
+            self
+
+        } // fn with_flag_type()
+
+
         pub fn build(self) -> World<B, F, P> {
 
+            let mut flag_end = self.flags
+                .values()
+                .map(|range| range.end)
+                .max()
+                .unwrap_or(0u8);
+
+            let typed_flags = self.flag_types
+                .into_iter()
+                .map(|(id, mut flag_type)| {
+                    flag_type.range = flag_end..flag_end + flag_type.range.end;
+                    flag_end        = flag_type.range.end;
+                    (id, flag_type)
+                }).collect::<HashMap<TypeId, FlagType>>();
+
+            let flag_ranges = self.flags
+                .values()
+                .chain(typed_flags.values().map(|flag_type| &flag_type.range))
+                .collect::<Vec<&Range<u8>>>();
+
+            flag_ranges
+                .iter()
+                .for_each(|range| assert!(
+                    range.start < range.end,
+                    "WARNING: flag range {:?} is empty!", range
+                )); // for_each()
+
+            flag_ranges
+                .iter()
+                .enumerate()
+                .for_each(|(index, a)| flag_ranges[index + 1..]
+                    .iter()
+                    .for_each(|b| assert!(
+                        a.end <= b.start || b.end <= a.start,
+                        "WARNING: flag ranges {:?} and {:?} are overlapping!", a, b
+                    ))); // for_each()
+
             assert!(
-                self.component_count + usize::from(
-                    match self.flags.iter().max_by(|(_, a), (_, b)| a.start.cmp(&b.end)) {
-                        Some(max) => max.1.end,
-                        None      => 0u8,
-                    } // match ..
-                ) < usize::from(B::BITS),
+                self.component_count + usize::from(flag_end) <= usize::from(B::BITS),
                 "WARNING: entity bitmask is overflowing!\n consider using a larger bit count!"
             );
 
             World {
                 components:         self.components,
                 flags:              self.flags,
+                flag_types:         typed_flags,
                 component_columns:  self.component_columns,
                 component_pointers: self.component_pointers,
-                entities:           HashMap::default(),
+                requirements:       self.requirements,
+                names:              self.names,
+                indexes:            self.indexes,
+                dirty_indexes:      RefCell::default(),
+                entities:           BTreeMap::default(),
                 next_entity_id:     0usize,
             } // World
         } // fn build()
     } // impl WorldBuilder
+
+
+    fn erase_bound<K: 'static>(bound: Bound<&K>) -> Bound<&dyn Any> {
+        match bound {
+            Bound::Included(key) => Bound::Included(key as &dyn Any),
+            Bound::Excluded(key) => Bound::Excluded(key as &dyn Any),
+            Bound::Unbounded     => Bound::Unbounded,
+        } // match ..
+    } // fn erase_bound()
@@ -0,0 +1,148 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use rust_ecs::{World, WorldError, WorldSnapshot, WorldDelta, EntityMap, Component, Replicate};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Position(i32);
+
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Local(u8);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Position {}
+    impl Component for Local {}
+
+
+    impl Replicate for Position {
+        fn encode(&self, bytes: &mut Vec<u8>) { bytes.extend_from_slice(&self.0.to_le_bytes()) }
+        fn decode(bytes: &[u8]) -> Option<Self> { Some(Position(i32::from_le_bytes(bytes.try_into().ok()?))) }
+    } // impl Replicate ..
+
+
+    fn world() -> World<u32, u8, u8> {
+        World::builder()
+            .with_component::<Position>()
+            .with_component::<Local>()
+            .with_flag(0u8, 0..2)
+            .with_replicated::<Position>()
+            .build()
+    } // fn world()
+
+
+    fn transmit(delta: &WorldDelta<u32>) -> WorldDelta<u32> {
+
+        let received = WorldDelta::from_bytes(&delta.to_bytes()).expect("Failed to decode a delta!");
+        assert!(received == *delta);
+        received
+
+    } // fn transmit()
+
+
+    #[test]
+    fn delta_round_trip() {
+
+        let mut server     = world();
+        let mut client     = world();
+        let mut entity_map = EntityMap::new();
+
+        client.new_entity().with_component(Local(9)).build();
+        let a = server.new_entity().with_component(Position(1)).with_component(Local(1)).build();
+        let b = server.new_entity().with_component(Position(2)).with_flag(0, Some(1)).build();
+
+        let delta = server.diff(&WorldSnapshot::empty());
+        assert_eq!(delta.get_spawned_entities(), vec![a, b]);
+        assert_eq!(delta.changed_component_count(), 2usize);
+
+        client.apply_delta(&transmit(&delta), &mut entity_map).unwrap();
+        let (local_a, local_b) = (entity_map.get_local_entity(a).unwrap(), entity_map.get_local_entity(b).unwrap());
+        assert_eq!(entity_map.get_server_entity(local_a), Some(a));
+        assert_eq!(client.get_entity_component::<Position>(local_a).unwrap().borrow().0, 1);
+        assert!(!client.entity_has_component::<Local>(local_a));
+        assert_eq!(client.get_entity_flag_variant(local_b, 0), Some(1));
+
+        let snapshot = server.snapshot();
+        assert!(server.diff(&snapshot).is_empty());
+
+        server.modify_component::<Position, _>(a, |position| position.0 = 5);
+        server.delete_entity_component::<Position>(b);
+        server.remove_entity_flag(b, 0, None);
+        server.delete_entity_component::<Local>(a);
+        let c = server.new_entity().with_component(Position(3)).build();
+
+        let delta = server.diff(&snapshot);
+        assert_eq!(delta.changed_component_count(), 2usize);
+
+        client.apply_delta(&transmit(&delta), &mut entity_map).unwrap();
+        assert_eq!(client.get_entity_component::<Position>(local_a).unwrap().borrow().0, 5);
+        assert!(!client.entity_has_component::<Position>(local_b));
+        assert_eq!(client.get_entity_flag_variant(local_b, 0), None);
+        assert_eq!(client.get_entity_component::<Position>(entity_map.get_local_entity(c).unwrap()).unwrap().borrow().0, 3);
+
+        let snapshot = server.snapshot();
+        server.delete_entity(a);
+
+        let delta = server.diff(&snapshot);
+        assert_eq!(delta.get_despawned_entities(), vec![a]);
+
+        client.apply_delta(&transmit(&delta), &mut entity_map).unwrap();
+        assert!(!client.contains_entity(local_a));
+        assert_eq!(entity_map.get_local_entity(a), None);
+        assert_eq!(entity_map.get_server_entity(local_a), None);
+        assert_eq!(client.new_query().build().get_entities().len(), 3usize);
+
+    } // fn delta_round_trip()
+
+
+    #[test]
+    fn rejected_delta_leaves_the_world_untouched() {
+
+        let mut server     = world();
+        let mut entity_map = EntityMap::new();
+        let mut validated  = World::<u32, u8, u8>::builder()
+            .with_component::<Position>()
+            .with_component::<Local>()
+            .with_flag(0u8, 0..2)
+            .with_replicated::<Position>()
+            .with_validator::<Position>(|position| match position.0 >= 0 {
+                true  => Ok(()),
+                false => Err(String::from("negative position")),
+            }).build();
+        let mut unique = World::<u32, u8, u8>::builder()
+            .with_component::<Position>()
+            .with_component::<Local>()
+            .with_flag(0u8, 0..2)
+            .with_replicated::<Position>()
+            .with_unique::<Position>()
+            .build();
+
+        let held = unique.new_entity().with_component(Position(0)).build();
+        server.new_entity().with_component(Position(1)).build();
+        let invalid = server.new_entity().with_component(Position(-1)).build();
+        let delta   = server.diff(&WorldSnapshot::empty());
+
+        assert!(matches!(validated.apply_delta(&delta, &mut entity_map), Err(WorldError::InvalidComponent { .. })));
+        assert!(validated.new_query().build().get_entities().is_empty());
+        assert!(entity_map.is_empty());
+
+        assert!(matches!(unique.apply_delta(&delta, &mut entity_map), Err(WorldError::UniqueViolation { holder, .. }) if holder == held));
+        assert_eq!(unique.new_query().build().get_entities(), vec![held]);
+        assert!(entity_map.is_empty());
+
+        server.delete_entity(invalid);
+        let delta = server.diff(&WorldSnapshot::empty());
+        validated.apply_delta(&delta, &mut entity_map).unwrap();
+        assert_eq!(validated.new_query().with_component::<Position>().build().get_entities().len(), 1usize);
+
+    } // fn rejected_delta_leaves_the_world_untouched()
@@ -0,0 +1,308 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use rust_ecs::{
+        World, WorldError, WorldSnapshot, WorldDelta, EntityMap, Entity, Component,
+        Replicate, ReplicationRule, ReplicationServer, ReplicationClient, LoopbackTransport, Transport, ClientId,
+    }; // use ..
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Position(i32);
+
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Health(u8);
+
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Secret(u8);
+
+
+    type TestWorld = World<u32, u8, u8>;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Position {}
+    impl Component for Health {}
+    impl Component for Secret {}
+
+
+    impl Replicate for Position {
+        fn encode(&self, bytes: &mut Vec<u8>) { bytes.extend_from_slice(&self.0.to_le_bytes()) }
+        fn decode(bytes: &[u8]) -> Option<Self> { Some(Position(i32::from_le_bytes(bytes.try_into().ok()?))) }
+    } // impl Replicate ..
+
+
+    impl Replicate for Health {
+        fn encode(&self, bytes: &mut Vec<u8>) { bytes.push(self.0) }
+        fn decode(bytes: &[u8]) -> Option<Self> { match bytes { [health] => Some(Health(*health)), _ => None } }
+    } // impl Replicate ..
+
+
+    impl Replicate for Secret {
+        fn encode(&self, bytes: &mut Vec<u8>) { bytes.push(self.0) }
+        fn decode(bytes: &[u8]) -> Option<Self> { match bytes { [secret] => Some(Secret(*secret)), _ => None } }
+    } // impl Replicate ..
+
+
+    fn world() -> TestWorld {
+        World::builder()
+            .with_component::<Position>()
+            .with_component::<Health>()
+            .with_component::<Secret>()
+            .with_replicated::<Position>()
+            .with_replication::<Health>(ReplicationRule::Always)
+            .with_replication::<Secret>(ReplicationRule::OwnerOnly)
+            .build()
+    } // fn world()
+
+
+    fn position(world: &TestWorld, entity: Entity) -> Option<i32> { world.get_entity_component::<Position>(entity).map(|position| position.borrow().0) }
+
+
+    #[test]
+    fn on_change_is_not_resent() {
+
+        let mut server_world = world();
+        let mut server       = ReplicationServer::new();
+        let mut transport    = LoopbackTransport::new();
+        let client           = server.add_client();
+
+        let entity = server_world.new_entity().with_component(Position(1)).build();
+
+        server.update(&server_world, &mut transport);
+        assert_eq!(transport.receive(client).unwrap().changed_component_count(), 1usize);
+
+        server.update(&server_world, &mut transport);
+        assert_eq!(transport.pending_count(client), 0usize);
+
+        server_world.modify_component::<Position, _>(entity, |position| position.0 = 1);
+        server.update(&server_world, &mut transport);
+        assert_eq!(transport.pending_count(client), 0usize);
+
+        server_world.modify_component::<Position, _>(entity, |position| position.0 = 2);
+        server.update(&server_world, &mut transport);
+        assert_eq!(transport.receive(client).unwrap().changed_component_count(), 1usize);
+
+        server_world.add_component_to_entity(Health(3), entity);
+        server.update(&server_world, &mut transport);
+        server.update(&server_world, &mut transport);
+        assert_eq!(transport.pending_count(client), 2usize);
+        assert!((0..2).all(|_| transport.receive(client).unwrap().changed_component_count() == 1usize));
+
+    } // fn on_change_is_not_resent()
+
+
+    #[test]
+    fn owner_only_reaches_the_owner() {
+
+        let mut server_world = world();
+        let mut server       = ReplicationServer::new();
+        let mut transport    = LoopbackTransport::new();
+        let (owner, other)   = (server.add_client(), server.add_client());
+        let mut clients      = [(ReplicationClient::new(owner), world()), (ReplicationClient::new(other), world())];
+
+        let entity = server_world.new_entity().with_component(Position(1)).with_component(Secret(7)).build();
+        server.set_owner(entity, owner);
+
+        server.update(&server_world, &mut transport);
+        clients
+            .iter_mut()
+            .for_each(|(client, world)| assert_eq!(client.receive(world, &mut transport), Ok(1usize)));
+
+        let [(owner_client, owner_world), (other_client, other_world)] = &mut clients;
+        let (owner_local, other_local) = (owner_client.get_local_entity(entity).unwrap(), other_client.get_local_entity(entity).unwrap());
+        assert_eq!(owner_world.get_entity_component::<Secret>(owner_local).unwrap().borrow().0, 7);
+        assert!(!other_world.entity_has_component::<Secret>(other_local));
+        assert_eq!(position(other_world, other_local), Some(1));
+
+        server.set_owner(entity, other);
+        server.update(&server_world, &mut transport);
+        owner_client.receive(owner_world, &mut transport).unwrap();
+        other_client.receive(other_world, &mut transport).unwrap();
+        assert!(!owner_world.entity_has_component::<Secret>(owner_local));
+        assert_eq!(other_world.get_entity_component::<Secret>(other_local).unwrap().borrow().0, 7);
+
+        server_world.delete_entity(entity);
+        server.update(&server_world, &mut transport);
+        assert_eq!(server.get_owner(entity), None);
+
+    } // fn owner_only_reaches_the_owner()
+
+
+    #[test]
+    fn interest_leave_and_reenter() {
+
+        let mut server_world = world();
+        let mut server       = ReplicationServer::new();
+        let mut transport    = LoopbackTransport::new();
+        let id               = server.add_client();
+        let mut client       = ReplicationClient::new(id);
+        let mut client_world = world();
+
+        server.set_interest(id, |world: &TestWorld, entity| position(world, entity).is_some_and(|position| position < 50));
+
+        let near = server_world.new_entity().with_component(Position(1)).build();
+        let far  = server_world.new_entity().with_component(Position(100)).build();
+
+        server.update(&server_world, &mut transport);
+        client.receive(&mut client_world, &mut transport).unwrap();
+        let local = client.get_local_entity(near).unwrap();
+        assert_eq!(client.get_local_entity(far), None);
+
+        server_world.modify_component::<Position, _>(near, |position| position.0 = 60);
+        server_world.modify_component::<Position, _>(far, |position| position.0 = 2);
+        server.update(&server_world, &mut transport);
+        client.receive(&mut client_world, &mut transport).unwrap();
+        assert!(!client_world.contains_entity(local));
+        assert_eq!(client.get_local_entity(near), None);
+        assert_eq!(position(&client_world, client.get_local_entity(far).unwrap()), Some(2));
+
+        server_world.modify_component::<Position, _>(near, |position| position.0 = 3);
+        server.update(&server_world, &mut transport);
+        client.receive(&mut client_world, &mut transport).unwrap();
+        let reentered = client.get_local_entity(near).unwrap();
+        assert_ne!(reentered, local);
+        assert_eq!(position(&client_world, reentered), Some(3));
+        assert_eq!(client_world.new_query().build().get_entities().len(), 2usize);
+
+    } // fn interest_leave_and_reenter()
+
+
+    #[test]
+    fn entity_mapping_through_loopback() {
+
+        let mut server_world = world();
+        let mut server       = ReplicationServer::new();
+        let mut transport    = LoopbackTransport::new();
+        let id               = server.add_client();
+        let mut client       = ReplicationClient::new(ClientId::new(id.id()));
+        let mut client_world = world();
+
+        client_world.new_entity().with_component(Position(-1)).build();
+        let entities = (0..4)
+            .map(|index| server_world.new_entity().with_component(Position(index)).build())
+            .collect::<Vec<Entity>>();
+
+        server.update(&server_world, &mut transport);
+        server_world.delete_entity(entities[1]);
+        server.update(&server_world, &mut transport);
+        assert_eq!(client.receive(&mut client_world, &mut transport), Ok(2usize));
+
+        assert_eq!(client.get_local_entity(entities[1]), None);
+        entities
+            .iter()
+            .filter(|entity| **entity != entities[1])
+            .for_each(|entity| {
+                let local = client.get_local_entity(*entity).unwrap();
+                assert_ne!(local, *entity);
+                assert_eq!(client.get_server_entity(local), Some(*entity));
+                assert_eq!(position(&client_world, local), Some(entity.id() as i32));
+            }); // for_each()
+
+        assert_eq!(client.get_entity_map().len(), 3usize);
+
+    } // fn entity_mapping_through_loopback()
+
+
+    #[test]
+    fn bad_deltas_are_rejected() {
+
+        let mut server_world = world();
+        let mut client_world = world();
+        let mut entity_map   = EntityMap::new();
+
+        let entity = server_world.new_entity().with_component(Position(1)).build();
+        let delta  = server_world.diff(&WorldSnapshot::empty());
+        let bytes  = delta.to_bytes();
+
+        assert!(matches!(WorldDelta::<u32>::from_bytes(&bytes[..bytes.len() - 1usize]), Err(WorldError::MalformedDelta(_))));
+        assert!(matches!(WorldDelta::<u32>::from_bytes(&[bytes.as_slice(), &[0u8]].concat()), Err(WorldError::MalformedDelta(_))));
+
+        let snapshot = server_world.snapshot();
+        server_world.modify_component::<Position, _>(entity, |position| position.0 = 2);
+        let update = server_world.diff(&snapshot);
+        assert_eq!(client_world.apply_delta(&update, &mut entity_map), Err(WorldError::UnknownEntity(entity)));
+        assert!(client_world.new_query().build().get_entities().is_empty());
+
+        let truncated = [&bytes[..bytes.len() - 8usize], &[3u8, 0u8, 0u8, 0u8, 1u8, 2u8, 3u8]].concat();
+        assert!(matches!(
+            client_world.apply_delta(&WorldDelta::from_bytes(&truncated).unwrap(), &mut entity_map),
+            Err(WorldError::MalformedDelta(_)),
+        )); // assert!()
+        assert!(client_world.new_query().build().get_entities().is_empty());
+
+        client_world.apply_delta(&delta, &mut entity_map).unwrap();
+        client_world.apply_delta(&update, &mut entity_map).unwrap();
+        assert_eq!(position(&client_world, entity_map.get_local_entity(entity).unwrap()), Some(2));
+
+    } // fn bad_deltas_are_rejected()
+
+
+    #[test]
+    fn deltas_are_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<WorldDelta<u32>>();
+    } // fn deltas_are_send()
+
+
+    #[test]
+    fn failed_delta_recovers_through_resync() {
+
+        let mut server_world = world();
+        let mut server       = ReplicationServer::new();
+        let mut transport    = LoopbackTransport::new();
+        let id               = server.add_client();
+        let mut client       = ReplicationClient::new(id);
+        let mut client_world = World::builder()
+            .with_component::<Position>()
+            .with_component::<Health>()
+            .with_component::<Secret>()
+            .with_replicated::<Position>()
+            .with_replication::<Health>(ReplicationRule::Always)
+            .with_replication::<Secret>(ReplicationRule::OwnerOnly)
+            .with_validator::<Position>(|position| match position.0 >= 0 {
+                true  => Ok(()),
+                false => Err(String::from("negative position")),
+            }).build();
+
+        let kept    = server_world.new_entity().with_component(Position(1)).build();
+        let removed = server_world.new_entity().with_component(Position(2)).build();
+        server.update(&server_world, &mut transport);
+        assert_eq!(client.receive(&mut client_world, &mut transport), Ok(1usize));
+        let removed_local = client.get_local_entity(removed).unwrap();
+
+        server_world.modify_component::<Position, _>(kept, |position| position.0 = -1);
+        server_world.delete_entity(removed);
+        let spawned = server_world.new_entity().with_component(Position(3)).build();
+        server.update(&server_world, &mut transport);
+        assert!(matches!(client.receive(&mut client_world, &mut transport), Err(WorldError::InvalidComponent { .. })));
+        assert!(client.is_desynced());
+        assert!(client_world.contains_entity(removed_local));
+        assert_eq!(client.get_local_entity(spawned), None);
+
+        server_world.modify_component::<Position, _>(kept, |position| position.0 = 5);
+        server.update(&server_world, &mut transport);
+        assert_eq!(client.receive(&mut client_world, &mut transport), Ok(0usize));
+        assert!(client.is_desynced());
+
+        server.resync_client(id);
+        server.update(&server_world, &mut transport);
+        assert_eq!(client.receive(&mut client_world, &mut transport), Ok(1usize));
+        assert!(!client.is_desynced());
+        assert_eq!(position(&client_world, client.get_local_entity(kept).unwrap()), Some(5));
+        assert_eq!(position(&client_world, client.get_local_entity(spawned).unwrap()), Some(3));
+        assert!(!client_world.contains_entity(removed_local));
+        assert_eq!(client.get_local_entity(removed), None);
+        assert_eq!(client_world.new_query().build().get_entities().len(), 2usize);
+
+    } // fn failed_delta_recovers_through_resync()
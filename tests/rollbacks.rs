@@ -0,0 +1,122 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use rust_ecs::{World, Component, Shared};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, PartialEq, Hash, Debug)]
+    struct Position(i32);
+
+
+    #[derive(Clone, PartialEq, Hash, Debug)]
+    struct Velocity(i32);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Component for Position {}
+    impl Component for Velocity {}
+
+
+    fn world() -> World<u32, u8, u8> {
+        World::builder()
+            .with_component::<Position>()
+            .with_component::<Velocity>()
+            .with_flag(0u8, 0..2)
+            .with_rollback::<Position>()
+            .with_rollback::<Velocity>()
+            .with_checksum::<Position>()
+            .with_checksum::<Velocity>()
+            .build()
+    } // fn world()
+
+
+    fn step(world: &mut World<u32, u8, u8>) {
+
+        world.new_query()
+            .with_component::<Velocity>()
+            .build()
+            .get_entities()
+            .into_iter()
+            .for_each(|entity| {
+                let velocity = world.get_entity_component::<Velocity>(entity).unwrap().borrow().0;
+                world.modify_component::<Position, _>(entity, |position| position.0 += velocity);
+            }); // for_each()
+
+    } // fn step()
+
+
+    #[test]
+    fn restore_round_trip() {
+
+        let mut world = world();
+        let a         = world.new_entity().with_component(Position(1)).with_component(Velocity(2)).build();
+        let b         = world.new_entity().with_component(Position(5)).with_flag(0, Some(1)).build();
+
+        let snapshot = world.snapshot();
+        let checksum = world.checksum();
+
+        step(&mut world);
+        world.delete_entity(b);
+        let c = world.new_entity().with_component(Position(7)).with_component(Velocity(1)).build();
+        step(&mut world);
+
+        let diverged = world.checksum();
+        assert_ne!(diverged, checksum);
+
+        world.restore(&snapshot);
+        assert_eq!(world.checksum(), checksum);
+        assert_eq!(world.get_entity_component::<Position>(a).unwrap().borrow().0, 1);
+        assert_eq!(world.get_entity_component::<Position>(b).unwrap().borrow().0, 5);
+        assert_eq!(world.get_entity_flag_variant(b, 0), Some(1));
+        assert!(!world.contains_entity(c));
+
+        step(&mut world);
+        world.delete_entity(b);
+        assert_eq!(world.new_entity().with_component(Position(7)).with_component(Velocity(1)).build(), c);
+        step(&mut world);
+        assert_eq!(world.checksum(), diverged);
+
+        world.restore(&snapshot);
+        world.restore(&snapshot);
+        assert_eq!(world.checksum(), checksum);
+
+    } // fn restore_round_trip()
+
+
+    #[test]
+    fn restore_keeps_components_shared() {
+
+        let mut world = world();
+        let shared    = Shared::new(Position(5));
+        let a         = world.new_entity().with_shared_component(&shared).build();
+        let b         = world.new_entity().with_shared_component(&shared).build();
+        let c         = world.new_entity().with_component(Position(1)).build();
+
+        let snapshot = world.snapshot();
+        let checksum = world.checksum();
+
+        world.modify_component::<Position, _>(a, |position| position.0 = 9);
+        assert_eq!(world.get_entity_component::<Position>(b).unwrap().borrow().0, 9);
+        world.delete_entity(b);
+        world.modify_component::<Position, _>(c, |position| position.0 = 2);
+
+        world.restore(&snapshot);
+        assert_eq!(world.checksum(), checksum);
+        assert_eq!(shared.borrow().0, 5);
+        assert_eq!(world.get_sharer_count::<Position>(a), 2usize);
+        assert!(shared.shares_with(world.get_entity_component::<Position>(b).unwrap()));
+
+        world.modify_component::<Position, _>(b, |position| position.0 = 3);
+        assert_eq!(world.get_entity_component::<Position>(a).unwrap().borrow().0, 3);
+        assert_eq!(world.get_entity_component::<Position>(c).unwrap().borrow().0, 1);
+        assert_eq!(shared.borrow().0, 3);
+
+    } // fn restore_keeps_components_shared()